@@ -1,34 +1,21 @@
-advent_of_code::solution!(1);
+advent_of_code::solution!(2025, 1, both);
 
-pub fn part_one(input: &str) -> Option<u64> {
+/// Turns the dial once, counting the rotations that stop at zero for part one, and the ones that pass it for part two.
+pub fn solve(input: &str) -> (Option<u64>, Option<u64>) {
     let mut position: i64 = 50;
-    let mut times_at_zero = 0;
-    let lines = parse(input);
-
-    lines.into_iter().for_each(|(dir, steps)| {
-        let (new_position, _at_zero) = do_move(position, steps, dir == 'R');
-        position = new_position;
-        if position == 0 {
-            times_at_zero += 1;
-        }
-    });
-
-    Some(times_at_zero)
-}
-
-pub fn part_two(input: &str) -> Option<u64> {
-    let lines = parse(input);
-
+    let mut stops_at_zero: u64 = 0;
     let mut times_at_zero: u64 = 0;
-    let mut position: i64 = 50;
 
-    lines.into_iter().for_each(|(dir, steps)| {
+    parse(input).into_iter().for_each(|(dir, steps)| {
         let (new_position, at_zero) = do_move(position, steps, dir == 'R');
         position = new_position;
         times_at_zero += at_zero;
+        if position == 0 {
+            stops_at_zero += 1;
+        }
     });
 
-    Some(times_at_zero)
+    (Some(stops_at_zero), Some(times_at_zero))
 }
 
 fn do_move(position: i64, steps: i64, is_positive: bool) -> (i64, u64) {
@@ -70,13 +57,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let (result, _) = solve(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let (_, result) = solve(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(26));
     }
 }
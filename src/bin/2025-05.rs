@@ -1,11 +1,10 @@
-advent_of_code::solution!(5);
+advent_of_code::solution!(2025, 5, parse = parse_input);
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let (fresh_ingredients, available_ids) = parse_input(input);
+pub fn part_one((fresh_ingredients, available_ids): &(Vec<(u64, u64)>, Vec<u64>)) -> Option<u64> {
     let mut fresh_available: Vec<u64> = Vec::new();
 
-    for id in available_ids {
-        if id_is_fresh(&fresh_ingredients, id) {
+    for &id in available_ids {
+        if id_is_fresh(fresh_ingredients, id) {
             fresh_available.push(id);
         }
     }
@@ -13,9 +12,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(fresh_available.len() as u64)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (fresh_ingredients, _) = parse_input(input);
-
+pub fn part_two((fresh_ingredients, _): &(Vec<(u64, u64)>, Vec<u64>)) -> Option<u64> {
     let mut sum = 0;
 
     for (start, end) in fresh_ingredients {
@@ -25,7 +22,7 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(sum)
 }
 
-fn id_is_fresh(fresh_ingredients: &Vec<(u64, u64)>, id: u64) -> bool {
+fn id_is_fresh(fresh_ingredients: &[(u64, u64)], id: u64) -> bool {
     for (start, end) in fresh_ingredients {
         if id >= *start && id <= *end {
             return true;
@@ -94,13 +91,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file("examples", YEAR, DAY)));
         assert_eq!(result, Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file("examples", YEAR, DAY)));
         assert_eq!(result, Some(14));
     }
 }
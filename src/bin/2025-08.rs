@@ -1,11 +1,17 @@
 use std::collections::HashSet;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2025, 8, parse = parse_input, params = Params);
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let (sorted_permutations, _) = parse_input(input);
-    let line_count = input.lines().count();
-    let desired_pairs: usize = if line_count == 20 { 10 } else { 1000 };
+advent_of_code::params! {
+    /// Number of closest pairs of junction boxes to connect. The example connects 10 pairs.
+    pairs: usize = 1000,
+}
+
+/// Pairs of junction boxes sorted by their distance, and the junction boxes.
+type Parsed = (Vec<(u64, usize, usize)>, Vec<(i64, i64, i64)>);
+
+pub fn part_one((sorted_permutations, _): &Parsed, params: &Params) -> Option<u64> {
+    let desired_pairs = params.pairs;
 
     let mut pair_count = 0;
 
@@ -29,9 +35,8 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(result)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (sorted_permutations, junction_boxes) = parse_input(input);
-    let line_count = input.lines().count();
+pub fn part_two((sorted_permutations, junction_boxes): &Parsed, _: &Params) -> Option<u64> {
+    let line_count = junction_boxes.len();
 
     let mut pair_count = 0;
     let mut circuits: Vec<HashSet<usize>> = Vec::with_capacity(3);
@@ -53,8 +58,8 @@ pub fn part_two(input: &str) -> Option<u64> {
 }
 
 fn add_to_circuit(circuits: &mut Vec<HashSet<usize>>, pair: (u64, usize, usize)) {
-    let left_index_option = is_in_circuit(pair.1, &circuits);
-    let right_index_option = is_in_circuit(pair.2, &circuits);
+    let left_index_option = is_in_circuit(pair.1, circuits);
+    let right_index_option = is_in_circuit(pair.2, circuits);
 
     if let Some(left_index) = left_index_option {
         if let Some(right_index) = right_index_option {
@@ -77,7 +82,7 @@ fn add_to_circuit(circuits: &mut Vec<HashSet<usize>>, pair: (u64, usize, usize))
     }
 }
 
-fn parse_input(input: &str) -> (Vec<(u64, usize, usize)>, Vec<(i64, i64, i64)>) {
+fn parse_input(input: &str) -> Parsed {
     let mut junction_boxes: Vec<(i64, i64, i64)> = Vec::new();
 
     for line in input.lines() {
@@ -110,20 +115,3 @@ fn parse_input(input: &str) -> (Vec<(u64, usize, usize)>, Vec<(i64, i64, i64)>)
 fn is_in_circuit(box_index: usize, circuits: &[HashSet<usize>]) -> Option<usize> {
     circuits.iter().position(|v| v.contains(&box_index))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(40));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(25272));
-    }
-}
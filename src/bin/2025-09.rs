@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(2025, 9);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let outer_points = parse_input(input);
@@ -273,13 +273,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(50));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(24));
     }
 }
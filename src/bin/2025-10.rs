@@ -1,18 +1,18 @@
 use std::collections::VecDeque;
 use z3::{ast::Int, Optimize, SatResult};
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(2025, 10);
 
 pub fn part_one(input: &str) -> Option<u64> {
-    Some(input.lines().map(|l| solve_line_p1(l)).sum())
+    Some(input.lines().map(solve_line_p1).sum())
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    Some(input.lines().map(|l| solve_line_p2(l)).sum())
+    Some(input.lines().map(solve_line_p2).sum())
 }
 
 fn solve_line_p1(line: &str) -> u64 {
-    let mut parts = line.split_whitespace().into_iter();
+    let mut parts = line.split_whitespace();
 
     let desired_pattern = parts.next().expect("missing pattern");
     let light_count = desired_pattern.len() - 2;
@@ -108,14 +108,14 @@ fn solve_line_p2(line: &str) -> u64 {
        .collect();
    let zero = Int::from_i64(0);
    for light_index in &light_indices {
-       opt.assert(&light_index.ge(&zero));
+       opt.assert(light_index.ge(&zero));
    }
 
    // Constraints per light/counter
-   for light_index in 0..targets.len() {
+   for (light_index, target) in targets.iter().enumerate() {
        let mut terms: Vec<&Int> = Vec::new();
        for (button_index, button) in buttons.iter().enumerate() {
-           if button.iter().any(|&i| i == light_index) {
+           if button.contains(&light_index) {
                terms.push(&light_indices[button_index]);
            }
        }
@@ -124,8 +124,8 @@ fn solve_line_p2(line: &str) -> u64 {
        } else {
            Int::add(&terms)
        };
-       let rhs = Int::from_i64(targets[light_index]);
-       opt.assert(&left.eq(&rhs));
+       let rhs = Int::from_i64(*target);
+       opt.assert(left.eq(&rhs));
    }
 
    // Objective: minimize total presses
@@ -161,13 +161,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(7));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(33));
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(2025, 11);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let (mut graph, inverse_graph, id_list) = parse_input(input);
@@ -151,6 +151,7 @@ fn calculate_can_reach_set(
     seen
 }
 
+#[allow(clippy::type_complexity)]
 fn parse_input(input: &str) -> (HashMap<usize, Vec<usize>>, HashMap<usize, Vec<usize>>, Vec<&str>) {
     let mut ids = HashSet::new();
     for line in input.lines() {
@@ -245,31 +246,15 @@ fn get_number_paths_with_mandatory_visits(
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
-        assert_eq!(result, Some(5));
-    }
-
     #[test]
     fn test_part_one_real() {
-        let result = part_one(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_one(&advent_of_code::template::read_file("inputs", YEAR, DAY));
         assert_eq!(result, Some(670));
     }
 
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(2));
-    }
-
     #[test]
     fn test_part_two_real() {
-        let result = part_two(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_two(&advent_of_code::template::read_file("inputs", YEAR, DAY));
         assert_eq!(result, Some(670));
     }
 }
@@ -1,4 +1,6 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, answer, download, inputs, read, scaffold, solve, time, verify,
+};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
@@ -6,9 +8,29 @@ use advent_of_code::template::Day;
 #[cfg(feature = "today")]
 use std::process;
 
+// NOTE: `cargo scaffold` adds new days to this list. Listed solutions run in-process with `cargo all` and `cargo time`.
+advent_of_code::days! {
+    day_2025_01 => "bin/2025-01.rs",
+    day_2025_02 => "bin/2025-02.rs",
+    day_2025_03 => "bin/2025-03.rs",
+    day_2025_04 => "bin/2025-04.rs",
+    day_2025_05 => "bin/2025-05.rs",
+    day_2025_06 => "bin/2025-06.rs",
+    day_2025_07 => "bin/2025-07.rs",
+    day_2025_08 => "bin/2025-08.rs",
+    day_2025_09 => "bin/2025-09.rs",
+    day_2025_10 => "bin/2025-10.rs",
+    day_2025_11 => "bin/2025-11.rs",
+    day_2025_12 => "bin/2025-12.rs",
+}
+
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::commands::time;
+    use advent_of_code::template::runner::{
+        BenchOptions, parse_budget, parse_param, parse_timeout,
+    };
+    use advent_of_code::template::{Day, InputSource, Year, config};
+    use std::{process, time::Duration};
 
     pub enum AppArguments {
         Download {
@@ -27,52 +49,132 @@ mod args {
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            force: bool,
+            input: InputSource,
+            params: Vec<(String, String)>,
+            timeout: Option<Duration>,
         },
         All {
             release: bool,
+            isolated: bool,
+            inputs: Option<String>,
+            timeout: Option<Duration>,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            isolated: bool,
+            bench: BenchOptions,
+            timeout: Option<Duration>,
+            compare: Option<f64>,
+        },
+        TimeHistory {
+            day: Day,
         },
+        Verify {
+            release: bool,
+            isolated: bool,
+            timeout: Option<Duration>,
+        },
+        Answer {
+            day: Day,
+            part: u8,
+            answer: String,
+        },
+        InputsCheck,
         #[cfg(feature = "today")]
         Today,
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    /// Parses the command and the year it applies to, which defaults to `AOC_YEAR`.
+    pub fn parse() -> Result<(Year, AppArguments), Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+
+        let year = args.opt_value_from_str("--year")?.or(config().year).ok_or(
+            "no year given. Pass `--year <year>`, set `AOC_YEAR` or set `year` in aoc.toml.",
+        )?;
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                isolated: args.contains("--isolated"),
+                inputs: args.opt_value_from_str("--inputs")?,
+                timeout: timeout(&mut args)?,
+            },
+            Some("time") if args.contains("--history") => AppArguments::TimeHistory {
+                day: free_day(&mut args, year)?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let isolated = args.contains("--isolated");
+
+                let mut bench = BenchOptions::default();
+                if let Some(budget) = args.opt_value_from_str::<_, String>("--budget")? {
+                    bench.budget = parse_budget(&budget)
+                        .ok_or("`--budget` expects a non-negative number of seconds.")?;
+                }
+                if let Some(min_samples) = args.opt_value_from_str::<_, u128>("--min-samples")? {
+                    bench.min_samples = min_samples.clamp(1, bench.max_samples);
+                }
+
+                let max_regression = args.opt_value_from_str("--max-regression")?;
+                let compare = (args.contains("--compare") || max_regression.is_some())
+                    .then(|| max_regression.unwrap_or(time::DEFAULT_MAX_REGRESSION));
 
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    day: args
+                        .opt_free_from_str::<String>()?
+                        .map(|day| year.parse_day(&day))
+                        .transpose()?,
                     store,
+                    isolated,
+                    bench,
+                    timeout: timeout(&mut args)?,
+                    compare,
                 }
             }
+            Some("verify") => AppArguments::Verify {
+                release: args.contains("--release"),
+                isolated: args.contains("--isolated"),
+                timeout: timeout(&mut args)?,
+            },
+            Some("answer") => AppArguments::Answer {
+                day: free_day(&mut args, year)?,
+                part: args.free_from_str()?,
+                answer: args.free_from_str()?,
+            },
+            Some("inputs") => match args.subcommand()?.as_deref() {
+                Some("check") => AppArguments::InputsCheck,
+                _ => {
+                    eprintln!("Unknown inputs command, expected `inputs check`.");
+                    process::exit(1);
+                }
+            },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                day: free_day(&mut args, year)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                day: free_day(&mut args, year)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                day: free_day(&mut args, year)?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+                day: free_day(&mut args, year)?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
+                force: args.contains("--force"),
                 dhat: args.contains("--dhat"),
+                input: input_source(&mut args)?,
+                params: args.values_from_fn("--param", parse_param)?,
+                timeout: timeout(&mut args)?,
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -91,7 +193,43 @@ mod args {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok((year, app_args))
+    }
+
+    /// Parses the input to solve from `--input <name>` or `--input-file <path>`, the downloaded input by default.
+    fn input_source(
+        args: &mut pico_args::Arguments,
+    ) -> Result<InputSource, Box<dyn std::error::Error>> {
+        let name: Option<String> = args.opt_value_from_str("--input")?;
+        let path: Option<String> = args.opt_value_from_str("--input-file")?;
+
+        match (name, path) {
+            (Some(_), Some(_)) => Err("pass either `--input` or `--input-file`, not both.".into()),
+            (Some(name), None) => Ok(InputSource::named(&name)),
+            (None, Some(path)) => Ok(InputSource::file(&path)),
+            (None, None) => Ok(InputSource::Default),
+        }
+    }
+
+    /// Parses the time a part may run from `--timeout <seconds>`, the timeout of the config by default.
+    fn timeout(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Duration>, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str::<_, String>("--timeout")? {
+            Some(secs) => Ok(Some(
+                parse_timeout(&secs).ok_or("`--timeout` expects a positive number of seconds.")?,
+            )),
+            None => Ok(config().timeout),
+        }
+    }
+
+    /// Parses a day of the event of `year`, so the error states the days of that event.
+    fn free_day(
+        args: &mut pico_args::Arguments,
+        year: Year,
+    ) -> Result<Day, Box<dyn std::error::Error>> {
+        let day: String = args.free_from_str()?;
+        Ok(year.parse_day(&day)?)
     }
 }
 
@@ -101,19 +239,59 @@ fn main() {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+        Ok((year, args)) => match args {
+            AppArguments::All {
+                release,
+                isolated,
+                inputs: None,
+                timeout,
+            } => all::handle(year, &registry(), release, isolated, timeout),
+            AppArguments::All {
+                release,
+                isolated,
+                inputs: Some(pattern),
+                timeout,
+            } => all::handle_inputs(year, &registry(), release, isolated, timeout, &pattern),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                isolated,
+                bench,
+                timeout,
+                compare,
+            } => time::handle(
+                year,
+                &registry(),
+                day,
+                all,
+                store,
+                isolated,
+                &bench,
+                timeout,
+                compare,
+            ),
+            AppArguments::TimeHistory { day } => time::history(year, day),
+            AppArguments::Verify {
+                release,
+                isolated,
+                timeout,
+            } => {
+                verify::handle(year, &registry(), release, isolated, timeout);
+            }
+            AppArguments::Answer { day, part, answer } => answer::handle(year, day, part, &answer),
+            AppArguments::Download { day } => download::handle(year, day),
+            AppArguments::InputsCheck => inputs::handle_check(year),
+            AppArguments::Read { day } => read::handle(year, day),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(year, day, overwrite);
                 if download {
-                    download::handle(day);
+                    download::handle(year, day);
+                    scaffold::add_example(year, day);
                 }
             }
             AppArguments::Solve {
@@ -121,19 +299,26 @@ fn main() {
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                force,
+                input,
+                params,
+                timeout,
+            } => solve::handle(
+                year, day, release, dhat, submit, force, &input, &params, timeout,
+            ),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                    Some((year, day)) => {
+                        scaffold::handle(year, day, false);
+                        download::handle(year, day);
+                        scaffold::add_example(year, day);
+                        read::handle(year, day)
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run during the event, between the 1st of \
+                            december and its last day. Please use `scaffold` with a specific day."
                         );
                         process::exit(1)
                     }
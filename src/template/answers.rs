@@ -0,0 +1,293 @@
+/// Module that stores the correct answers of each day, so refactored solutions can be checked against them.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+fn get_path(year: Year) -> PathBuf {
+    year.data_dir().join("answers.json")
+}
+
+/// Correct answers for a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayAnswers {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Correct answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub data: Vec<DayAnswers>,
+}
+
+/// Result of comparing an answer with the recorded one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnswerCheck {
+    Correct,
+    Wrong { expected: String, actual: String },
+    Unknown,
+}
+
+impl Display for AnswerCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswerCheck::Correct => f.write_str("✅"),
+            AnswerCheck::Wrong { expected, actual } => {
+                write!(f, "❌ expected {expected}, got {actual}")
+            }
+            AnswerCheck::Unknown => f.write_str("❔ unknown"),
+        }
+    }
+}
+
+impl Answers {
+    /// Dehydrate answers to the JSON file of a year.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        fs::create_dir_all(year.data_dir())?;
+        let mut file = fs::File::create(get_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from the JSON file of a year. If not present, returns no answers.
+    /// A file that can't be read or parsed is an error, so it is never replaced by fewer answers.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        Self::read_from_path(&get_path(year))
+    }
+
+    fn read_from_path(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                Answers::try_from(contents).map_err(|e| format!("{}: {e}", path.display()))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    /// The recorded answer of a part, if any.
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        let answers = self.data.iter().find(|a| a.day == day)?;
+        match part {
+            1 => answers.part_1.as_deref(),
+            2 => answers.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Record the answer of a part, replacing a previous one.
+    pub fn set(&mut self, day: Day, part: u8, answer: &str) {
+        let index = match self.data.iter().position(|a| a.day == day) {
+            Some(index) => index,
+            None => {
+                self.data.push(DayAnswers {
+                    day,
+                    part_1: None,
+                    part_2: None,
+                });
+                self.data.sort_unstable_by_key(|a| a.day);
+                self.data.iter().position(|a| a.day == day).unwrap()
+            }
+        };
+
+        let answer = Some(answer.trim().to_string());
+        match part {
+            1 => self.data[index].part_1 = answer,
+            2 => self.data[index].part_2 = answer,
+            _ => {}
+        }
+    }
+
+    /// Compare an answer with the recorded one. Surrounding whitespace is ignored.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> AnswerCheck {
+        match self.get(day, part) {
+            Some(expected) if expected == answer.trim() => AnswerCheck::Correct,
+            Some(expected) => AnswerCheck::Wrong {
+                expected: expected.to_string(),
+                actual: answer.trim().to_string(),
+            },
+            None => AnswerCheck::Unknown,
+        }
+    }
+}
+
+/// Record an answer in the answers file of a year.
+/// A file that can't be parsed is left as is, instead of being replaced by this answer.
+pub fn record(year: Year, day: Day, part: u8, answer: &str) -> Result<(), String> {
+    let mut answers = Answers::read_from_file(year)?;
+    answers.set(day, part, answer);
+    answers.store_file(year).map_err(|e| e.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(DayAnswers::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<&DayAnswers> for JsonValue {
+    fn from(value: &DayAnswers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        for (key, answer) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            map.insert(
+                key.into(),
+                match answer {
+                    Some(x) => JsonValue::String(x.clone()),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for DayAnswers {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answers to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answers.day to be a Day struct.")?;
+
+        let answer = |key: &str| -> Result<Option<String>, String> {
+            match json.get(key) {
+                None | Some(JsonValue::Null) => Ok(None),
+                Some(JsonValue::String(s)) => Ok(Some(s.clone())),
+                // NOTE: numbers are floats in JSON, which can't hold every answer, e.g. integers above 2^53.
+                Some(JsonValue::Number(_)) => Err(format!(
+                    "Expected answers.{key} to be a string, write numbers in quotes."
+                )),
+                Some(_) => Err(format!("Expected answers.{key} to be null or a string.")),
+            }
+        };
+
+        Ok(DayAnswers {
+            day,
+            part_1: answer("part_1")?,
+            part_2: answer("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{AnswerCheck, Answers};
+    use crate::day;
+    use std::fs;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn checks_answers() {
+        let mut answers = Answers::default();
+        answers.set(day!(2), 1, "42\n");
+
+        assert_eq!(answers.check(day!(2), 1, "42"), AnswerCheck::Correct);
+        assert_eq!(
+            answers.check(day!(2), 1, "41"),
+            AnswerCheck::Wrong {
+                expected: "42".into(),
+                actual: "41".into()
+            }
+        );
+        assert_eq!(answers.check(day!(2), 2, "42"), AnswerCheck::Unknown);
+        assert_eq!(answers.check(day!(1), 1, "42"), AnswerCheck::Unknown);
+    }
+
+    #[test]
+    fn keeps_days_sorted() {
+        let mut answers = Answers::default();
+        answers.set(day!(3), 2, "b");
+        answers.set(day!(1), 1, "a");
+        answers.set(day!(3), 1, "c");
+
+        assert_eq!(answers.data.len(), 2);
+        assert_eq!(answers.data[0].day, day!(1));
+        assert_eq!(answers.get(day!(3), 1), Some("c"));
+        assert_eq!(answers.get(day!(3), 2), Some("b"));
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 2, "1234");
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(Answers::try_from(json).unwrap(), answers);
+    }
+
+    #[test]
+    fn rejects_numeric_answers() {
+        let json = r#"{ "data": [{ "day": "05", "part_1": 1234, "part_2": null }] }"#.to_string();
+        assert!(Answers::try_from(json).is_err());
+    }
+
+    #[test]
+    fn reads_missing_files_as_empty() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.json", std::process::id()));
+        assert_eq!(Answers::read_from_path(&path), Ok(Answers::default()));
+    }
+
+    #[test]
+    fn rejects_unparsable_files() {
+        let path =
+            std::env::temp_dir().join(format!("aoc-answers-bad-{}.json", std::process::id()));
+        fs::write(&path, "{ \"data\": [").unwrap();
+        let result = Answers::read_from_path(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+}
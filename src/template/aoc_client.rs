@@ -0,0 +1,403 @@
+/// Client for the Advent of Code website.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::markdown::puzzle_to_markdown;
+use crate::template::submissions::SubmitOutcome;
+use crate::template::{Day, Year, config};
+
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    /// No session cookie was found in the environment or the session file.
+    MissingSession,
+    /// The server responded with an error status.
+    Status(u16),
+    /// The server could not be reached.
+    Transport(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Set `AOC_SESSION` or save it to \"~/.adventofcode.session\"."
+            ),
+            AocClientError::Status(404) => {
+                write!(f, "the puzzle was not found, it might not be unlocked yet.")
+            }
+            AocClientError::Status(400) => {
+                write!(
+                    f,
+                    "the server rejected the request, the session cookie might have expired."
+                )
+            }
+            AocClientError::Status(status) => {
+                write!(f, "the server responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "could not reach the server: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, _) => AocClientError::Status(status),
+            ureq::Error::Transport(t) => AocClientError::Transport(t.to_string()),
+        }
+    }
+}
+
+/// Response to a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubmitResponse {
+    /// [`None`] if the response could not be recognized.
+    pub outcome: Option<SubmitOutcome>,
+    /// Text of the response page.
+    pub message: String,
+}
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: Year,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str, year: Year) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            year,
+        }
+    }
+
+    /// Creates a client for a year from the environment:
+    ///  - the session cookie is read from `AOC_SESSION`, or from the file at `AOC_SESSION_FILE`,
+    ///    `~/.adventofcode.session` or `~/.config/adventofcode.session`.
+    ///  - the base url is read from the [`config`], e.g. to test against a local server.
+    pub fn from_env(year: Year) -> Result<Self, AocClientError> {
+        let session = read_session().ok_or(AocClientError::MissingSession)?;
+        Ok(Self::new(&config().base_url, &session, year))
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day.into_inner())
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?;
+        Ok(response.into_string()?)
+    }
+
+    /// Fetches the puzzle input of a day.
+    pub fn input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+
+    /// Fetches the HTML of the puzzle page of a day. Once part one is solved, it also contains part two.
+    pub fn puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&self.day_url(day))
+    }
+
+    /// Submits an answer and reads the response page.
+    /// If the server tells that the part is not the current one, the puzzle page is checked to tell whether
+    /// it was already solved.
+    pub fn submit(
+        &self,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmitResponse, AocClientError> {
+        let html = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(day)))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer.trim())])?
+            .into_string()?;
+
+        let message = articles(&html)
+            .iter()
+            .map(|article| html_to_text(article))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        // NOTE: the answer was already posted, so a failed lookup of the puzzle must not lose the outcome. It then
+        // stays a wrong level.
+        let outcome = match SubmitOutcome::parse(&message) {
+            Some(SubmitOutcome::WrongLevel)
+                if self
+                    .puzzle(day)
+                    .is_ok_and(|html| solved_parts(&html) >= part) =>
+            {
+                Some(SubmitOutcome::AlreadySolved)
+            }
+            outcome => outcome,
+        };
+
+        Ok(SubmitResponse { outcome, message })
+    }
+}
+
+/// Downloads the puzzle description of a day and stores it as Markdown. Returns the Markdown.
+pub fn store_puzzle(client: &AocClient, day: Day) -> Result<String, AocClientError> {
+    let markdown = puzzle_to_markdown(&client.puzzle(day)?);
+    let path = get_puzzle_path(client.year, day);
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, &markdown)?;
+    Ok(markdown)
+}
+
+pub fn get_input_path(year: Year, day: Day) -> String {
+    path_to_string(year.data_dir().join("inputs").join(format!("{day}.txt")))
+}
+
+pub fn get_puzzle_path(year: Year, day: Day) -> String {
+    path_to_string(year.data_dir().join("puzzles").join(format!("{day}.md")))
+}
+
+fn path_to_string(path: PathBuf) -> String {
+    path.to_string_lossy().into_owned()
+}
+
+fn read_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION")
+        && !session.trim().is_empty()
+    {
+        return Some(session.trim().to_string());
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"));
+
+    let candidates: Vec<PathBuf> = [
+        env::var_os("AOC_SESSION_FILE").map(PathBuf::from),
+        home.as_ref()
+            .map(|home| PathBuf::from(home).join(".adventofcode.session")),
+        home.as_ref()
+            .map(|home| PathBuf::from(home).join(".config/adventofcode.session")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    candidates
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .map(|s| s.trim().to_string())
+        .find(|s| !s.is_empty())
+}
+
+/// Counts the solved parts on a puzzle page, each of which shows its answer.
+pub fn solved_parts(html: &str) -> u8 {
+    let count = html.matches("Your puzzle answer was").count();
+    u8::try_from(count).unwrap_or(u8::MAX)
+}
+
+/// Extracts the contents of all `<article>` elements, which hold the puzzle description or the response text.
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let content_start = start + open_end + 1;
+        let Some(end) = rest[content_start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[content_start..content_start + end]);
+        rest = &rest[content_start + end + "</article>".len()..];
+    }
+
+    articles
+}
+
+/// Converts HTML to plain text by removing all tags and decoding entities.
+pub fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    decode_entities(text.trim())
+}
+
+/// Decodes the HTML entities that show up in puzzle descriptions.
+pub fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{AocClient, AocClientError, articles, html_to_text, solved_parts};
+    use crate::template::submissions::{Hint, SubmitOutcome};
+    use crate::{day, year};
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serves a single request with the given status and body.
+    /// Returns the base url of the server and a handle that returns the raw request.
+    fn mock_server(status: u16, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut body_bytes = vec![0; content_length];
+            reader.read_exact(&mut body_bytes).unwrap();
+            request.push_str(&String::from_utf8(body_bytes).unwrap());
+
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn downloads_inputs() {
+        let (url, server) = mock_server(200, "1\n2\n3\n");
+        let client = AocClient::new(&url, "abc", year!(2025));
+
+        assert_eq!(client.input(day!(9)).unwrap(), "1\n2\n3\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2025/day/9/input HTTP/1.1"));
+        assert!(request.contains("session=abc"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (url, server) = mock_server(
+            200,
+            "<main><article><p>That's not the right answer; your answer is too low. <a href=\"/2025/day/1\">[Return]</a></p></article></main>",
+        );
+        let client = AocClient::new(&url, "abc", year!(2025));
+
+        let submitted = client.submit(day!(1), 2, "1234\n").unwrap();
+        assert_eq!(
+            submitted.outcome,
+            Some(SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooLow)
+            })
+        );
+        assert!(submitted.message.ends_with("[Return]"));
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2025/day/1/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=1234"));
+    }
+
+    #[test]
+    fn keeps_wrong_level_if_the_puzzle_lookup_fails() {
+        // NOTE: the mock server answers a single request, so the lookup of the puzzle after the submission fails.
+        let (url, server) = mock_server(
+            200,
+            "<main><article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article></main>",
+        );
+        let client = AocClient::new(&url, "abc", year!(2025));
+
+        let submitted = client.submit(day!(1), 1, "1234").unwrap();
+        assert_eq!(submitted.outcome, Some(SubmitOutcome::WrongLevel));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn reports_error_statuses() {
+        let (url, _server) = mock_server(404, "Not found");
+        let client = AocClient::new(&url, "abc", year!(2025));
+
+        assert!(matches!(
+            client.puzzle(day!(12)),
+            Err(AocClientError::Status(404))
+        ));
+    }
+
+    #[test]
+    fn extracts_articles() {
+        let html = r#"<main><article class="day-desc"><h2>--- Day 1 ---</h2></article><p>You solved part one.</p><article class="day-desc"><p>Part &lt;2&gt;</p></article></main>"#;
+        let articles = articles(html);
+        assert_eq!(articles.len(), 2);
+        assert_eq!(html_to_text(articles[0]), "--- Day 1 ---");
+        assert_eq!(html_to_text(articles[1]), "Part <2>");
+    }
+
+    #[test]
+    fn counts_solved_parts() {
+        let html =
+            "<article><p>Part one</p></article><p>Your puzzle answer was <code>14</code>.</p>";
+        assert_eq!(solved_parts(html), 1);
+        assert_eq!(solved_parts("<article><p>Part one</p></article>"), 0);
+    }
+}
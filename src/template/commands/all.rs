@@ -1,5 +1,192 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::{collections::HashSet, iter, process, time::Duration};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::inputs::{self, matches_pattern};
+use crate::template::run_multi::{DayRun, MultiRun, run_multi};
+use crate::template::runner::DaySolution;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, InputSource, PartStatus, Year, all_days};
+
+pub fn handle(
+    year: Year,
+    registry: &[DaySolution],
+    is_release: bool,
+    is_isolated: bool,
+    timeout: Option<Duration>,
+) {
+    run_multi(
+        year,
+        &all_days(year).collect(),
+        registry,
+        is_release,
+        None,
+        timeout,
+        is_isolated,
+        &InputSource::Default,
+    );
+}
+
+/// Runs every day against each of its inputs whose name matches `pattern`, e.g. `*` for all of them,
+/// and prints the answers of the inputs side by side.
+pub fn handle_inputs(
+    year: Year,
+    registry: &[DaySolution],
+    is_release: bool,
+    is_isolated: bool,
+    timeout: Option<Duration>,
+    pattern: &str,
+) {
+    let available: Vec<(Day, Vec<InputSource>)> = all_days(year)
+        .map(|day| {
+            let inputs = inputs::available(year, day)
+                .into_iter()
+                .filter(|input| matches_pattern(pattern, &input.to_string()))
+                .collect::<Vec<_>>();
+            (day, inputs)
+        })
+        .filter(|(_, inputs)| !inputs.is_empty())
+        .collect();
+
+    let mut sources: Vec<InputSource> = available
+        .iter()
+        .flat_map(|(_, inputs)| inputs.iter().cloned())
+        .collect();
+    sources.sort();
+    sources.dedup();
+
+    if sources.is_empty() {
+        eprintln!("No inputs of {year} match \"{pattern}\".");
+        process::exit(1);
+    }
+
+    let runs: Vec<MultiRun> = sources
+        .iter()
+        .enumerate()
+        .map(|(index, input)| {
+            if index > 0 {
+                println!();
+            }
+            println!("{ANSI_BOLD}Input {input}{ANSI_RESET}\n");
+
+            let days: HashSet<Day> = available
+                .iter()
+                .filter(|(_, inputs)| inputs.contains(input))
+                .map(|(day, _)| *day)
+                .collect();
+
+            run_multi(
+                year,
+                &days,
+                registry,
+                is_release,
+                None,
+                timeout,
+                is_isolated,
+                input,
+            )
+        })
+        .collect();
+
+    let days: Vec<Day> = available.iter().map(|(day, _)| *day).collect();
+    print_answers(&days, &sources, &runs);
+}
+
+/// Prints a table with a row per day and a column with the answers of each input.
+fn print_answers(days: &[Day], sources: &[InputSource], runs: &[MultiRun]) {
+    let header = iter::once("Day".to_string()).chain(sources.iter().map(ToString::to_string));
+
+    let rows: Vec<Vec<String>> = iter::once(header.collect())
+        .chain(days.iter().map(|day| {
+            iter::once(day.to_string())
+                .chain(
+                    runs.iter()
+                        .map(|run| format_answers(run.days.iter().find(|d| d.day == *day))),
+                )
+                .collect()
+        }))
+        .collect();
+
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    println!("\n{ANSI_BOLD}Answers:{ANSI_RESET}");
+    for row in &rows {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", cells.join("  ").trim_end());
+    }
+}
+
+/// Answers of both parts of a day, e.g. `42 · 1337`. Days that did not run against the input show `-`.
+fn format_answers(run: Option<&DayRun>) -> String {
+    let Some(run) = run else {
+        return "-".into();
+    };
+
+    let answer = |part: u8| match run.report(part) {
+        Some(report) if report.status == PartStatus::Solved => match report.answer.as_deref() {
+            Some(answer) if answer.contains('\n') => "▼".to_string(),
+            Some(answer) => answer.to_string(),
+            None => "-".into(),
+        },
+        Some(report) if report.status == PartStatus::TimedOut => "⏱".into(),
+        Some(_) => "✖".into(),
+        None => "-".into(),
+    };
+
+    format!("{} · {}", answer(1), answer(2))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::format_answers;
+    use crate::day;
+    use crate::template::PartStatus;
+    use crate::template::run_multi::DayRun;
+    use crate::template::runner::PartReport;
+    use crate::template::stats::Stats;
+    use std::time::Duration;
+
+    fn report(part: u8, status: PartStatus, answer: Option<&str>) -> PartReport {
+        PartReport {
+            part,
+            status,
+            answer: answer.map(ToString::to_string),
+            message: None,
+            stats: Stats::single(Duration::from_nanos(10)),
+            combined: false,
+            benched: false,
+            heap: None,
+        }
+    }
+
+    #[test]
+    fn formats_answers_of_a_day() {
+        let run = DayRun {
+            day: day!(1),
+            reports: vec![
+                report(1, PartStatus::Solved, Some("42")),
+                report(2, PartStatus::NotImplemented, None),
+            ],
+            failures: vec![],
+        };
+
+        assert_eq!(format_answers(Some(&run)), "42 · ✖");
+        assert_eq!(format_answers(None), "-");
+
+        let timed_out = DayRun {
+            reports: vec![report(1, PartStatus::TimedOut, None)],
+            ..run
+        };
+        assert_eq!(format_answers(Some(&timed_out)), "⏱ · -");
+    }
 }
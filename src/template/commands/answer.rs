@@ -0,0 +1,20 @@
+use std::process;
+
+use crate::template::{Day, Year, answers};
+
+pub fn handle(year: Year, day: Day, part: u8, answer: &str) {
+    if !(1..=2).contains(&part) {
+        eprintln!("Part must be 1 or 2, got {part}.");
+        process::exit(1);
+    }
+
+    if let Err(e) = answers::record(year, day, part, answer) {
+        eprintln!("Failed to record the answer: {e}");
+        process::exit(1);
+    }
+
+    println!(
+        "Recorded \"{}\" as the answer of {year} day {day}, part {part}.",
+        answer.trim()
+    );
+}
@@ -1,16 +1,49 @@
-use crate::template::{Day, aoc_cli};
-use std::process;
+use crate::template::aoc_client::{AocClient, get_input_path, get_puzzle_path, store_puzzle};
+use crate::template::inputs;
+use crate::template::{Day, Year};
+use std::{fs, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
+pub fn handle(year: Year, day: Day) {
+    let client = AocClient::from_env(year).unwrap_or_else(|e| {
+        eprintln!("failed to create client: {e}");
         process::exit(1);
+    });
+
+    let input_path = get_input_path(year, day);
+    let puzzle_path = get_puzzle_path(year, day);
+
+    // NOTE: inputs never change, so an existing input is never downloaded again.
+    let has_input = fs::metadata(&input_path).is_ok_and(|m| m.len() > 0);
+
+    if !has_input {
+        let input = client.input(day).unwrap_or_else(|e| {
+            eprintln!("failed to download input: {e}");
+            process::exit(1);
+        });
+
+        if let Err(e) = fs::write(&input_path, &input) {
+            eprintln!("failed to write input: {e}");
+            process::exit(1);
+        }
+
+        if let Err(e) = inputs::record(year, day, input.as_bytes()) {
+            eprintln!("failed to record the checksum of the input: {e}");
+        }
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = store_puzzle(&client, day) {
+        eprintln!("failed to download puzzle: {e}");
         process::exit(1);
-    };
+    }
+
+    println!("---");
+    if has_input {
+        println!(
+            "🎄 Input \"{}\" already exists, skipped downloading it.",
+            &input_path
+        );
+    } else {
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    }
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
 }
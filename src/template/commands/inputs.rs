@@ -0,0 +1,44 @@
+use std::{fs, process};
+
+use crate::template::aoc_client::get_input_path;
+use crate::template::inputs::{InputState, Manifest};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Year, all_days};
+
+/// Compare the input file of a day with the checksum manifest of its year.
+pub fn check(manifest: &Manifest, year: Year, day: Day) -> InputState {
+    let contents = fs::read(get_input_path(year, day)).ok();
+    manifest.check(day, contents.as_deref())
+}
+
+/// Checks every input file of a year against the checksum manifest.
+/// Exits with an error if any input is empty, truncated, edited or missing, or if the manifest can't be read.
+pub fn handle_check(year: Year) {
+    let manifest = Manifest::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read the checksums of the inputs: {e}");
+        process::exit(1);
+    });
+
+    let states: Vec<(Day, InputState)> = all_days(year)
+        .map(|day| (day, check(&manifest, year, day)))
+        .filter(|(day, state)| {
+            *state != InputState::Untracked || fs::metadata(get_input_path(year, *day)).is_ok()
+        })
+        .collect();
+
+    println!("{ANSI_BOLD}Inputs {year}:{ANSI_RESET}");
+    for (day, state) in &states {
+        println!("{:<6}  {state}", day.to_string());
+    }
+
+    let problems = states
+        .iter()
+        .filter(|(_, state)| state.is_problem())
+        .count();
+
+    if problems > 0 {
+        eprintln!(
+            "\n{problems} input(s) differ from the downloaded ones. Delete them and run `cargo download <day>` again."
+        );
+        process::exit(1);
+    }
+}
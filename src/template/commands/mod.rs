@@ -1,6 +1,9 @@
 pub mod all;
+pub mod answer;
 pub mod download;
+pub mod inputs;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
 pub mod time;
+pub mod verify;
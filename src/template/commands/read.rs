@@ -1,17 +1,26 @@
 use std::process;
 
-use crate::template::{Day, aoc_cli};
+use crate::template::aoc_client::{AocClient, store_puzzle};
+use crate::template::commands::scaffold;
+use crate::template::markdown::render_ansi;
+use crate::template::{Day, Year};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
+/// Downloads the puzzle description, which includes part two once it is unlocked, and prints it. The example answers
+/// of an unlocked part are added to the example manifest.
+pub fn handle(year: Year, day: Day) {
+    let client = AocClient::from_env(year).unwrap_or_else(|e| {
+        eprintln!("failed to create client: {e}");
         process::exit(1);
-    }
+    });
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    match store_puzzle(&client, day) {
+        Ok(markdown) => {
+            print!("{}", render_ansi(&markdown));
+            scaffold::add_unlocked_examples(year, day, &markdown);
+        }
+        Err(e) => {
+            eprintln!("failed to download puzzle: {e}");
+            process::exit(1);
+        }
+    }
 }
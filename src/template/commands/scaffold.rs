@@ -1,14 +1,29 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
     process,
 };
 
-use crate::template::Day;
+use crate::template::aoc_client::{get_input_path, get_puzzle_path};
+use crate::template::example_manifest::{ExampleCase, ExampleManifest};
+use crate::template::examples::{extract_answer, extract_example};
+use crate::template::run_multi::{get_bin_name, get_path_for_bin};
+use crate::template::{Day, Year};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
+const MAIN_PATH: &str = "src/main.rs";
+const REGISTRY_START: &str = "advent_of_code::days! {";
+
+fn get_example_path(year: Year, day: Day) -> String {
+    year.data_dir()
+        .join("examples")
+        .join(format!("{day}.txt"))
+        .to_string_lossy()
+        .into_owned()
+}
+
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
@@ -19,6 +34,52 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
+/// Adds a day to the `days!` invocation in `src/main.rs`, keeping entries sorted.
+/// Returns [`None`] if the day is already registered.
+fn add_to_registry(main: &str, year: Year, day: Day) -> Result<Option<String>, String> {
+    let start = main
+        .find(REGISTRY_START)
+        .ok_or_else(|| format!("could not find `{REGISTRY_START}`."))?
+        + REGISTRY_START.len();
+    let end = start
+        + main[start..]
+            .find("\n}")
+            .ok_or("could not find the end of `days!`.")?;
+
+    let entry = format!(
+        "    day_{year}_{day} => \"bin/{}.rs\",",
+        get_bin_name(year, day)
+    );
+
+    let mut entries: Vec<&str> = main[start..end]
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .collect();
+
+    if entries.contains(&entry.as_str()) {
+        return Ok(None);
+    }
+
+    entries.push(&entry);
+    entries.sort_unstable();
+
+    let mut updated = main.to_string();
+    updated.replace_range(start..end, &format!("\n{}", entries.join("\n")));
+    Ok(Some(updated))
+}
+
+fn register_day(year: Year, day: Day) -> Result<bool, String> {
+    let main = fs::read_to_string(MAIN_PATH).map_err(|e| e.to_string())?;
+
+    match add_to_registry(&main, year, day)? {
+        Some(updated) => {
+            fs::write(MAIN_PATH, updated).map_err(|e| e.to_string())?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
 fn create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new()
         .write(true)
@@ -27,10 +88,26 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Creates an empty file, keeping an existing one. Returns whether the file was created.
+fn create_missing_file(path: &str) -> Result<bool, std::io::Error> {
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn handle(year: Year, day: Day, overwrite: bool) {
+    let input_path = get_input_path(year, day);
+    let example_path = get_example_path(year, day);
+    let module_path = get_path_for_bin(year, day);
+
+    for dir in ["inputs", "examples"] {
+        if let Err(e) = fs::create_dir_all(year.data_dir().join(dir)) {
+            eprintln!("Failed to create data directory: {e}");
+            process::exit(1);
+        }
+    }
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -42,6 +119,7 @@ pub fn handle(day: Day, overwrite: bool) {
 
     match file.write_all(
         MODULE_TEMPLATE
+            .replace("%YEAR%", &year.to_string())
             .replace("%DAY_NUMBER%", &day.into_inner().to_string())
             .as_bytes(),
     ) {
@@ -54,10 +132,25 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
+    match register_day(year, day) {
+        Ok(true) => {
+            println!("Registered day in \"{MAIN_PATH}\"");
+        }
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!("Failed to register day in \"{MAIN_PATH}\": {e}");
+            process::exit(1);
+        }
+    }
+
+    // NOTE: an existing input is kept, as downloading it again is not always possible.
+    match create_missing_file(&input_path) {
+        Ok(true) => {
             println!("Created empty input file \"{}\"", &input_path);
         }
+        Ok(false) => {
+            println!("Kept existing input file \"{}\"", &input_path);
+        }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
             process::exit(1);
@@ -75,5 +168,211 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+}
+
+/// Writes the example of the stored puzzle description to the (empty) example file
+/// and its expected answers to the example manifest of the day.
+pub fn add_example(year: Year, day: Day) {
+    let example_path = get_example_path(year, day);
+    let manifest_path = ExampleManifest::path(year, day);
+
+    let Ok(puzzle) = fs::read_to_string(get_puzzle_path(year, day)) else {
+        eprintln!("Could not read the puzzle description, skipping the example.");
+        return;
+    };
+
+    let example_is_empty = fs::read_to_string(&example_path).map_or(true, |s| s.trim().is_empty());
+
+    match extract_example(&puzzle) {
+        Some(example) if example_is_empty => match fs::write(&example_path, example) {
+            Ok(()) => println!("🎄 Wrote example to \"{}\".", &example_path),
+            Err(e) => eprintln!("Failed to write example file: {e}"),
+        },
+        Some(_) => println!(
+            "Example file \"{}\" is not empty, skipping the example.",
+            &example_path
+        ),
+        None => println!("Could not find an example in the puzzle description."),
+    }
+
+    let manifest = ExampleManifest {
+        cases: example_cases(&puzzle, day),
+    };
+
+    if manifest.cases.is_empty() {
+        return;
+    }
+
+    if manifest_path.exists() {
+        println!(
+            "Example manifest \"{}\" already exists, skipping the expected answers.",
+            manifest_path.display()
+        );
+        return;
+    }
+
+    let parts: Vec<u8> = manifest.cases.iter().map(|case| case.part).collect();
+    match fs::write(&manifest_path, manifest.to_toml(day)) {
+        Ok(()) => println!(
+            "🎄 Wrote expected example answers for part(s) {parts:?} to \"{}\".",
+            manifest_path.display()
+        ),
+        Err(e) => eprintln!("Failed to write example manifest: {e}"),
+    }
+}
+
+/// Adds the cases of parts that were unlocked since the example manifest of a day was written, e.g. part two once part
+/// one is solved. The cases are appended, so edits to the manifest are kept. Days without a manifest are skipped.
+pub fn add_unlocked_examples(year: Year, day: Day, puzzle: &str) {
+    let manifest_path = ExampleManifest::path(year, day);
+
+    let contents = match fs::read_to_string(&manifest_path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => return,
+        Err(e) => {
+            eprintln!("Failed to read the example manifest: {e}");
+            return;
+        }
+    };
+
+    match with_unlocked_cases(&contents, puzzle, day) {
+        Ok(None) => {}
+        Ok(Some((contents, parts))) => match fs::write(&manifest_path, contents) {
+            Ok(()) => println!(
+                "🎄 Added expected example answers for part(s) {parts:?} to \"{}\".",
+                manifest_path.display()
+            ),
+            Err(e) => eprintln!("Failed to write example manifest: {e}"),
+        },
+        Err(e) => eprintln!(
+            "Could not add the new example answers to \"{}\": {e}",
+            manifest_path.display()
+        ),
+    }
+}
+
+/// The manifest with the cases of the parts it has no case for yet appended, and those parts. Returns [`None`] if no
+/// part is missing.
+fn with_unlocked_cases(
+    contents: &str,
+    puzzle: &str,
+    day: Day,
+) -> Result<Option<(String, Vec<u8>)>, String> {
+    let manifest = ExampleManifest::from_toml(contents, day)?;
+
+    let unlocked = ExampleManifest {
+        cases: example_cases(puzzle, day)
+            .into_iter()
+            .filter(|case| !manifest.cases.iter().any(|c| c.part == case.part))
+            .collect(),
+    };
+
+    if unlocked.cases.is_empty() {
+        return Ok(None);
+    }
+
+    let separator = match contents.trim_end() {
+        "" => "",
+        _ if contents.ends_with('\n') => "\n",
+        _ => "\n\n",
+    };
+    let parts = unlocked.cases.iter().map(|case| case.part).collect();
+
+    Ok(Some((
+        format!("{contents}{separator}{}", unlocked.to_toml(day)),
+        parts,
+    )))
+}
+
+/// The cases of the example of a puzzle description, one for every part with a highlighted answer.
+fn example_cases(puzzle: &str, day: Day) -> Vec<ExampleCase> {
+    (1..=2)
+        .filter_map(|part| {
+            extract_answer(puzzle, part).map(|answer| ExampleCase {
+                file: format!("{day}.txt"),
+                part,
+                answer,
+                params: vec![],
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{add_to_registry, example_cases, with_unlocked_cases};
+    use crate::{day, year};
+
+    const MAIN: &str = "fn main() {}\n\nadvent_of_code::days! {\n    day_2025_01 => \"bin/2025-01.rs\",\n    day_2025_03 => \"bin/2025-03.rs\",\n}\n";
+
+    #[test]
+    fn adds_days_in_order() {
+        let updated = add_to_registry(MAIN, year!(2025), day!(2))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            updated,
+            "fn main() {}\n\nadvent_of_code::days! {\n    day_2025_01 => \"bin/2025-01.rs\",\n    day_2025_02 => \"bin/2025-02.rs\",\n    day_2025_03 => \"bin/2025-03.rs\",\n}\n"
+        );
+    }
+
+    #[test]
+    fn adds_days_of_other_years_in_order() {
+        let updated = add_to_registry(MAIN, year!(2024), day!(12))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            updated,
+            "fn main() {}\n\nadvent_of_code::days! {\n    day_2024_12 => \"bin/2024-12.rs\",\n    day_2025_01 => \"bin/2025-01.rs\",\n    day_2025_03 => \"bin/2025-03.rs\",\n}\n"
+        );
+    }
+
+    #[test]
+    fn skips_registered_days() {
+        assert_eq!(add_to_registry(MAIN, year!(2025), day!(3)).unwrap(), None);
+    }
+
+    #[test]
+    fn adds_to_empty_registry() {
+        let updated = add_to_registry("advent_of_code::days! {\n}\n", year!(2025), day!(1))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            updated,
+            "advent_of_code::days! {\n    day_2025_01 => \"bin/2025-01.rs\",\n}\n"
+        );
+    }
+
+    #[test]
+    fn lists_expected_answers() {
+        let cases = example_cases(include_str!("../fixtures/puzzle-both-parts.md"), day!(1));
+
+        assert_eq!(
+            cases
+                .iter()
+                .map(|case| (case.file.as_str(), case.part, case.answer.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("01.txt", 1, "14"), ("01.txt", 2, "19")]
+        );
+        assert!(example_cases("No answers.", day!(1)).is_empty());
+    }
+
+    #[test]
+    fn appends_unlocked_answers() {
+        let puzzle = include_str!("../fixtures/puzzle-both-parts.md");
+        let manifest = "# checked by hand\n[[example]]\npart = 1\nanswer = 14\n";
+
+        let (updated, parts) = with_unlocked_cases(manifest, puzzle, day!(1))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            updated,
+            "# checked by hand\n[[example]]\npart = 1\nanswer = 14\n\n[[example]]\npart = 2\nanswer = 19\n"
+        );
+        assert_eq!(parts, vec![2]);
+
+        assert_eq!(with_unlocked_cases(&updated, puzzle, day!(1)), Ok(None));
+        assert!(with_unlocked_cases("[[example]]\npart = 3", puzzle, day!(1)).is_err());
+    }
 }
@@ -1,9 +1,40 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
+use std::time::Duration;
 
-use crate::template::Day;
+use crate::template::commands::inputs;
+use crate::template::inputs::Manifest;
+use crate::template::run_multi::get_bin_name;
+use crate::template::{Day, InputSource, Year};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    force: bool,
+    input: &InputSource,
+    params: &[(String, String)],
+    timeout: Option<Duration>,
+) {
+    match Manifest::read_from_file(year) {
+        Ok(manifest) => {
+            let state = inputs::check(&manifest, year, day);
+            if input.is_default() && state.is_problem() {
+                eprintln!(
+                    "Warning: the input of {year} day {day} is {state}. Run `cargo inputs check` for details."
+                );
+            }
+        }
+        Err(e) => eprintln!("Warning: could not read the checksums of the inputs, {e}"),
+    }
+
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        get_bin_name(year, day),
+    ];
 
     if dhat {
         cmd_args.extend([
@@ -23,6 +54,22 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if force {
+        cmd_args.push("--force".to_string());
+    }
+
+    cmd_args.extend(input.to_args());
+
+    for (key, value) in params {
+        cmd_args.push("--param".to_string());
+        cmd_args.push(format!("{key}={value}"));
+    }
+
+    if let Some(timeout) = timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(timeout.as_secs_f64().to_string());
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -30,5 +77,9 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    // NOTE: the exit code tells the outcome of a submission.
+    let status = cmd.wait().unwrap();
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
 }
@@ -1,19 +1,42 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, process, time::Duration};
 
 use crate::template::run_multi::run_multi;
+use crate::template::runner::{BenchOptions, DaySolution};
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{Day, InputSource, Year, all_days, history, readme_benchmarks, regression};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Default for `--max-regression`, in percent.
+pub const DEFAULT_MAX_REGRESSION: f64 = 10.0;
+
+/// Passing `compare` as the maximum tolerated slowdown in percent compares the results with the stored timings.
+/// A larger slowdown of any part exits with an error, before anything is stored.
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    registry: &[DaySolution],
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    is_isolated: bool,
+    bench: &BenchOptions,
+    timeout: Option<Duration>,
+    compare: Option<f64>,
+) {
+    let stored_timings = Timings::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read the stored timings: {e}");
+        process::exit(1);
+    });
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                all_days(year).collect()
+            } else if compare.is_some() {
+                // when comparing, bench all days that have a baseline.
+                stored_timings.data.iter().map(|t| t.day).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
+                all_days(year)
                     .filter(|day| !stored_timings.is_day_complete(*day))
                     .collect()
             }
@@ -21,14 +44,46 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(
+        year,
+        &days_to_run,
+        registry,
+        true,
+        Some(bench),
+        timeout,
+        is_isolated,
+        &InputSource::Default,
+    )
+    .timings
+    .unwrap();
+
+    if let Some(max_regression) = compare {
+        let comparisons = regression::compare(&stored_timings, &timings);
+        regression::print_comparisons(&comparisons, max_regression);
+
+        let regressions = comparisons
+            .iter()
+            .filter(|c| c.is_regression(max_regression))
+            .count();
+
+        if regressions > 0 {
+            eprintln!(
+                "\n{regressions} part(s) got slower by more than {max_regression}%. Not storing benchmarks."
+            );
+            process::exit(1);
+        }
+    }
 
     if store {
+        if let Err(e) = history::append(year, &timings) {
+            eprintln!("Failed to append benchmarks to the history: {e}");
+        }
+
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -38,3 +93,8 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         }
     }
 }
+
+/// Prints the benchmarks of a day that were stored over time.
+pub fn history(year: Year, day: Day) {
+    history::print_history(day, &history::read_from_file(year, day));
+}
@@ -0,0 +1,212 @@
+use std::{collections::HashSet, fmt::Display, path::Path, process, time::Duration};
+
+use crate::template::answers::{AnswerCheck, Answers};
+use crate::template::run_multi::{DayRun, get_path_for_bin, run_multi};
+use crate::template::runner::DaySolution;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, InputSource, PartStatus, Year, all_days};
+
+/// Result of verifying a single part against its recorded answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    /// The part produced a different answer than the recorded one.
+    Mismatch,
+    /// The part did not produce an answer, but one is recorded.
+    Failed,
+    /// There is no recorded answer for the part.
+    Unknown,
+}
+
+impl Verdict {
+    pub fn is_failure(self) -> bool {
+        matches!(self, Verdict::Mismatch | Verdict::Failed)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => f.write_str("✅ pass"),
+            Verdict::Mismatch => f.write_str("❌ mismatch"),
+            Verdict::Failed => f.write_str("✖ failed"),
+            Verdict::Unknown => f.write_str("❔ unknown"),
+        }
+    }
+}
+
+/// Runs every scaffolded day and compares the answers with the recorded ones.
+/// Exits with an error if any part does not produce its recorded answer.
+pub fn handle(
+    year: Year,
+    registry: &[DaySolution],
+    is_release: bool,
+    is_isolated: bool,
+    timeout: Option<Duration>,
+) {
+    let days_to_run: HashSet<Day> = all_days(year)
+        .filter(|day| {
+            registry.iter().any(|s| s.year == year && s.day == *day)
+                || Path::new(&get_path_for_bin(year, *day)).exists()
+        })
+        .collect();
+
+    let run = run_multi(
+        year,
+        &days_to_run,
+        registry,
+        is_release,
+        None,
+        timeout,
+        is_isolated,
+        &InputSource::Default,
+    );
+    let answers = Answers::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read the answers: {e}");
+        process::exit(1);
+    });
+    let verdicts = verify(&answers, &run.days);
+
+    print_matrix(&verdicts);
+
+    let failures = verdicts
+        .iter()
+        .flat_map(|(_, parts)| parts)
+        .filter(|v| v.is_failure())
+        .count();
+
+    if failures > 0 {
+        eprintln!("\n{failures} part(s) did not produce their recorded answer.");
+        process::exit(1);
+    }
+}
+
+/// Compare the answers of every part that ran with the recorded answers.
+pub fn verify(answers: &Answers, runs: &[DayRun]) -> Vec<(Day, [Verdict; 2])> {
+    runs.iter()
+        .map(|run| {
+            let verdict = |part: u8| {
+                let report = run.report(part).filter(|r| r.status == PartStatus::Solved);
+
+                match report.and_then(|r| r.answer.as_deref()) {
+                    Some(answer) => match answers.check(run.day, part, answer) {
+                        AnswerCheck::Correct => Verdict::Pass,
+                        AnswerCheck::Wrong { .. } => Verdict::Mismatch,
+                        AnswerCheck::Unknown => Verdict::Unknown,
+                    },
+                    None if answers.get(run.day, part).is_some() => Verdict::Failed,
+                    None => Verdict::Unknown,
+                }
+            };
+
+            (run.day, [verdict(1), verdict(2)])
+        })
+        .collect()
+}
+
+fn print_matrix(verdicts: &[(Day, [Verdict; 2])]) {
+    println!("\n{ANSI_BOLD}Verification:{ANSI_RESET}");
+    println!("{:<6}  {:<12}  {:<12}", "Day", "Part 1", "Part 2");
+
+    for (day, [part_1, part_2]) in verdicts {
+        println!(
+            "{:<6}  {:<12}  {:<12}",
+            day.to_string(),
+            part_1.to_string(),
+            part_2.to_string()
+        );
+    }
+
+    let count = |verdict: Verdict| {
+        verdicts
+            .iter()
+            .flat_map(|(_, parts)| parts)
+            .filter(|v| **v == verdict)
+            .count()
+    };
+
+    println!(
+        "\n{} passed · {} mismatched · {} failed · {} unknown",
+        count(Verdict::Pass),
+        count(Verdict::Mismatch),
+        count(Verdict::Failed),
+        count(Verdict::Unknown)
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Verdict, verify};
+    use crate::day;
+    use crate::template::{
+        PartStatus,
+        answers::Answers,
+        run_multi::{DayFailure, DayRun},
+        runner::PartReport,
+        stats::Stats,
+    };
+    use std::time::Duration;
+
+    fn report(part: u8, answer: Option<&str>) -> PartReport {
+        PartReport {
+            part,
+            status: if answer.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::NotImplemented
+            },
+            answer: answer.map(Into::into),
+            message: None,
+            stats: Stats::single(Duration::from_nanos(1)),
+            combined: false,
+            benched: false,
+            heap: None,
+        }
+    }
+
+    #[test]
+    fn verifies_parts() {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 1, "42");
+        answers.set(day!(1), 2, "7");
+        answers.set(day!(2), 1, "1");
+
+        let runs = vec![
+            DayRun {
+                day: day!(1),
+                reports: vec![report(1, Some("42")), report(2, Some("8"))],
+                failures: vec![],
+            },
+            DayRun {
+                day: day!(2),
+                reports: vec![report(1, None), report(2, None)],
+                failures: vec![DayFailure::Part(1, PartStatus::NotImplemented)],
+            },
+        ];
+
+        assert_eq!(
+            verify(&answers, &runs),
+            vec![
+                (day!(1), [Verdict::Pass, Verdict::Mismatch]),
+                (day!(2), [Verdict::Failed, Verdict::Unknown]),
+            ]
+        );
+    }
+
+    #[test]
+    fn fails_crashed_days_with_recorded_answers() {
+        let mut answers = Answers::default();
+        answers.set(day!(3), 2, "5");
+
+        let runs = vec![DayRun {
+            day: day!(3),
+            reports: vec![],
+            failures: vec![DayFailure::Crashed("panicked".into())],
+        }];
+
+        let verdicts = verify(&answers, &runs);
+        assert_eq!(verdicts[0].1, [Verdict::Unknown, Verdict::Failed]);
+        assert!(verdicts[0].1[1].is_failure());
+    }
+}
@@ -0,0 +1,308 @@
+/// Module that loads the settings of the template from `aoc.toml`.
+///
+/// Every setting is resolved with the following precedence, from highest to lowest:
+///  1. command-line arguments (`--year`, `--budget`, `--timeout`), applied by the commands themselves.
+///  2. environment variables (`AOC_YEAR`, `AOC_DATA_DIR`, `AOC_README`, `AOC_BENCH_BUDGET`, `AOC_TIMEOUT`,
+///     `AOC_BASE_URL`).
+///  3. the config file, `aoc.toml` or the file at `AOC_CONFIG`.
+///  4. the defaults of [`Config::default`].
+use std::{
+    collections::BTreeMap, env, fmt::Display, fs, io, path::PathBuf, process, sync::OnceLock,
+    time::Duration,
+};
+
+use crate::template::Year;
+use toml::{Table, Value};
+
+use crate::template::runner;
+
+const DEFAULT_PATH: &str = "aoc.toml";
+
+/// Settings of the template.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// The year commands apply to if no `--year` is given.
+    pub year: Option<Year>,
+    /// Directory that holds the data of every year.
+    pub data_dir: PathBuf,
+    /// The readme that holds the benchmark tables.
+    pub readme: PathBuf,
+    /// Text of the markers around a benchmark table, followed by the year.
+    pub benchmark_marker: String,
+    /// Approximate time to spend on measuring a part with `cargo time`.
+    pub bench_budget: Duration,
+    /// Time a part may run before it is reported as timed out. Parts run without a timeout by default.
+    pub timeout: Option<Duration>,
+    /// Url of the advent of code website, e.g. to test against a local server.
+    pub base_url: String,
+    /// Last day of the events whose length differs from the default, see [`Year::last_day`].
+    pub event_days: BTreeMap<Year, u8>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: None,
+            data_dir: PathBuf::from("data"),
+            readme: PathBuf::from("README.md"),
+            benchmark_marker: "benchmarking table".into(),
+            bench_budget: Duration::from_secs(1),
+            timeout: None,
+            base_url: "https://adventofcode.com".into(),
+            event_days: BTreeMap::new(),
+        }
+    }
+}
+
+/// The config of this run. It is loaded once, exits if the config file is invalid.
+pub fn config() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    CONFIG.get_or_init(|| {
+        Config::load().unwrap_or_else(|e| {
+            eprintln!("Failed to load the config: {e}");
+            process::exit(1);
+        })
+    })
+}
+
+impl Config {
+    /// Reads the config file if present and applies the environment on top of it.
+    pub fn load() -> Result<Self, ConfigError> {
+        let path = env::var("AOC_CONFIG").unwrap_or_else(|_| DEFAULT_PATH.into());
+
+        let mut config = match fs::read_to_string(&path) {
+            Ok(contents) => Self::from_toml(&contents)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(ConfigError::IO(e)),
+        };
+
+        config.apply_env(|key| env::var(key).ok())?;
+        Ok(config)
+    }
+
+    /// Parses a config file. Settings that are not in the file keep their default.
+    pub fn from_toml(contents: &str) -> Result<Self, ConfigError> {
+        let mut config = Self::default();
+        let table = contents
+            .parse::<Table>()
+            .map_err(|e| ConfigError::Invalid(e.to_string()))?;
+
+        for (key, value) in settings(table, "") {
+            match (key.as_str(), value) {
+                ("year", Value::Integer(year)) => {
+                    config.year = Some(parse_year(&year.to_string())?);
+                }
+                ("paths.data", Value::String(path)) => config.data_dir = path.into(),
+                ("paths.readme", Value::String(path)) => config.readme = path.into(),
+                ("benchmarks.marker", Value::String(marker)) => config.benchmark_marker = marker,
+                ("benchmarks.budget", value @ (Value::Integer(_) | Value::Float(_))) => {
+                    config.bench_budget = parse_budget(&value.to_string())?;
+                }
+                ("run.timeout", value @ (Value::Integer(_) | Value::Float(_))) => {
+                    config.timeout = Some(parse_timeout(&value.to_string())?);
+                }
+                ("client.base_url", Value::String(url)) => config.base_url = url,
+                (key, value) if key.starts_with("events.") => {
+                    let year = parse_year(&key["events.".len()..])?;
+                    config
+                        .event_days
+                        .insert(year, parse_last_day(year, &value)?);
+                }
+                (key, _) => {
+                    let message = match key {
+                        "year" | "benchmarks.budget" | "run.timeout" => {
+                            format!("`{key}` should be a number.")
+                        }
+                        "paths.data" | "paths.readme" | "benchmarks.marker" | "client.base_url" => {
+                            format!("`{key}` should be a string.")
+                        }
+                        _ => format!("unexpected setting `{key}`."),
+                    };
+                    return Err(ConfigError::Invalid(message));
+                }
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Overrides settings with the environment variables returned by `var`.
+    pub fn apply_env(&mut self, var: impl Fn(&str) -> Option<String>) -> Result<(), ConfigError> {
+        let var = |key| var(key).filter(|value: &String| !value.trim().is_empty());
+
+        if let Some(year) = var("AOC_YEAR") {
+            self.year = Some(parse_year(&year)?);
+        }
+        if let Some(path) = var("AOC_DATA_DIR") {
+            self.data_dir = path.into();
+        }
+        if let Some(path) = var("AOC_README") {
+            self.readme = path.into();
+        }
+        if let Some(budget) = var("AOC_BENCH_BUDGET") {
+            self.bench_budget = parse_budget(&budget)?;
+        }
+        if let Some(timeout) = var("AOC_TIMEOUT") {
+            self.timeout = Some(parse_timeout(&timeout)?);
+        }
+        if let Some(url) = var("AOC_BASE_URL") {
+            self.base_url = url;
+        }
+
+        Ok(())
+    }
+}
+
+/// Flattens the tables of a config file into its settings, keyed by their dotted path, e.g. `paths.data`.
+fn settings(table: Table, prefix: &str) -> Vec<(String, Value)> {
+    table
+        .into_iter()
+        .flat_map(|(key, value)| {
+            let key = format!("{prefix}{key}");
+            match value {
+                Value::Table(table) => settings(table, &format!("{key}.")),
+                value => vec![(key, value)],
+            }
+        })
+        .collect()
+}
+
+fn parse_year(s: &str) -> Result<Year, ConfigError> {
+    s.trim()
+        .parse()
+        .map_err(|e| ConfigError::Invalid(format!("year: {e}.")))
+}
+
+fn parse_budget(s: &str) -> Result<Duration, ConfigError> {
+    runner::parse_budget(s)
+        .ok_or_else(|| ConfigError::Invalid("budget: expecting a number of seconds.".into()))
+}
+
+fn parse_last_day(year: Year, value: &Value) -> Result<u8, ConfigError> {
+    value
+        .as_integer()
+        .and_then(|day| u8::try_from(day).ok())
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| {
+            ConfigError::Invalid(format!("events.{year}: expecting a day between 1 and 25."))
+        })
+}
+
+fn parse_timeout(s: &str) -> Result<Duration, ConfigError> {
+    runner::parse_timeout(s).ok_or_else(|| {
+        ConfigError::Invalid("timeout: expecting a positive number of seconds.".into())
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug)]
+pub enum ConfigError {
+    IO(io::Error),
+    Invalid(String),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::IO(e) => write!(f, "could not read the config file: {e}"),
+            ConfigError::Invalid(message) => write!(f, "invalid config file, {message}"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Config;
+    use crate::year;
+    use std::{collections::BTreeMap, path::PathBuf, time::Duration};
+
+    const CONFIG: &str = r#"
+# Settings of the template.
+year = 2024
+
+[paths]
+data = "puzzles/data" # next to the solutions
+readme = "docs/README.md"
+
+[benchmarks]
+marker = "timings #2"
+budget = 0.5
+
+[run]
+timeout = 30
+
+[client]
+base_url = "http://localhost:8080"
+
+[events]
+2026 = 25
+"#;
+
+    #[test]
+    fn reads_config_file() {
+        let config = Config::from_toml(CONFIG).unwrap();
+
+        assert_eq!(config.year, Some(year!(2024)));
+        assert_eq!(config.data_dir, PathBuf::from("puzzles/data"));
+        assert_eq!(config.readme, PathBuf::from("docs/README.md"));
+        assert_eq!(config.benchmark_marker, "timings #2");
+        assert_eq!(config.bench_budget, Duration::from_millis(500));
+        assert_eq!(config.timeout, Some(Duration::from_secs(30)));
+        assert_eq!(config.base_url, "http://localhost:8080");
+        assert_eq!(config.event_days, BTreeMap::from([(year!(2026), 25)]));
+    }
+
+    #[test]
+    fn keeps_defaults_of_missing_settings() {
+        let config = Config::from_toml("[paths]\nreadme = \"docs/README.md\"\n").unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                readme: PathBuf::from("docs/README.md"),
+                ..Config::default()
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_config_files() {
+        assert!(Config::from_toml("year = 2014").is_err());
+        assert_eq!(
+            Config::from_toml("year = \"2024\"")
+                .unwrap_err()
+                .to_string(),
+            "invalid config file, `year` should be a number."
+        );
+        assert!(Config::from_toml("[paths]\nunknown = \"x\"").is_err());
+        assert!(Config::from_toml("[benchmarks\nbudget = 1").is_err());
+        assert!(Config::from_toml("[benchmarks]\nbudget = -1").is_err());
+        assert!(Config::from_toml("[run]\ntimeout = 0").is_err());
+        assert!(Config::from_toml("base_url").is_err());
+        assert!(Config::from_toml("[events]\n2026 = 26").is_err());
+        assert!(Config::from_toml("[events]\n2014 = 25").is_err());
+    }
+
+    #[test]
+    fn environment_overrides_config_file() {
+        let mut config = Config::from_toml(CONFIG).unwrap();
+        config
+            .apply_env(|key| match key {
+                "AOC_YEAR" => Some("2025".into()),
+                "AOC_BENCH_BUDGET" => Some("2".into()),
+                "AOC_TIMEOUT" => Some("1.5".into()),
+                "AOC_README" => Some(String::new()),
+                _ => None,
+            })
+            .unwrap();
+
+        assert_eq!(config.year, Some(year!(2025)));
+        assert_eq!(config.bench_budget, Duration::from_secs(2));
+        assert_eq!(config.timeout, Some(Duration::from_millis(1500)));
+        assert_eq!(config.readme, PathBuf::from("docs/README.md"));
+        assert_eq!(config.data_dir, PathBuf::from("puzzles/data"));
+    }
+}
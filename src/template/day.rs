@@ -5,16 +5,22 @@ use std::str::FromStr;
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
 
+use crate::template::Year;
+
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 12).
+/// The last day of the longest events.
+const MAX_DAY: u8 = 25;
+
+/// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// Most events since 2025 only have 12 days, see [`Year::last_day`].
 ///
 /// # Display
 /// This value displays as a two-digit number.
 ///
 /// ```
-/// # use advent_of_code::Day;
+/// # use advent_of_code::template::Day;
 /// let day = Day::new(8).unwrap();
 /// assert_eq!(day.to_string(), "08")
 /// ```
@@ -25,26 +31,34 @@ impl Day {
     /// Creates a [`Day`] from the provided value if it's in the valid range,
     /// returns [`None`] otherwise.
     pub const fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 12 {
+        if day == 0 || day > MAX_DAY {
             return None;
         }
         Some(Self(day))
     }
 
     /// Converts the [`Day`] into an [`u8`].
-    pub fn into_inner(self) -> u8 {
+    pub const fn into_inner(self) -> u8 {
         self.0
     }
+
+    /// Parses a day of an event that ends on `last_day`.
+    pub fn from_str_until(s: &str, last_day: u8) -> Result<Self, DayFromStrError> {
+        let error = DayFromStrError { last_day };
+        let day = s.parse().map_err(|_| error)?;
+        Self::new(day).filter(|_| day <= last_day).ok_or(error)
+    }
 }
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 12 of december, `None` otherwise.
-    pub fn today() -> Option<Self> {
+    /// Returns the current year and day if it's a day of this year's event in december, `None` otherwise.
+    pub fn today() -> Option<(Year, Self)> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 12 {
-            Self::new(u8::try_from(today.day()).ok()?)
+        let year = Year::new(u16::try_from(today.year()).ok()?)?;
+        if today.month() == 12 {
+            Some((year, year.day(u8::try_from(today.day()).ok()?)?))
         } else {
             None
         }
@@ -75,39 +89,43 @@ impl FromStr for Day {
     type Err = DayFromStrError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        Self::from_str_until(s, MAX_DAY)
     }
 }
 
 /// An error which can be returned when parsing a [`Day`].
-#[derive(Debug)]
-pub struct DayFromStrError;
+#[derive(Debug, Clone, Copy)]
+pub struct DayFromStrError {
+    last_day: u8,
+}
 
 impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 12")
+        write!(f, "expecting a day number between 1 and {}", self.last_day)
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 12.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// An iterator that yields every day of the event of a year, from the 1st to its last day.
+pub fn all_days(year: Year) -> AllDays {
+    AllDays::new(year)
 }
 
-/// An iterator that yields every day of advent from the 1st to the 12.
+/// An iterator that yields every day of the event of a year, from the 1st to its last day.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    pub fn new(year: Year) -> Self {
+        Self {
+            current: 1,
+            last: year.last_day(),
+        }
     }
 }
 
@@ -115,10 +133,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 12 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 12.
+        // NOTE: the iterator starts at 1 and we have verified that the value is not above the last day.
         let day = Day(self.current);
         self.current += 1;
 
@@ -134,7 +152,7 @@ macro_rules! day {
     ($day:expr) => {
         const {
             $crate::template::Day::new($day)
-                .expect("invalid day number, expecting a value between 1 and 12")
+                .expect("invalid day number, expecting a value between 1 and 25")
         }
     };
 }
@@ -144,10 +162,11 @@ macro_rules! day {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{Day, all_days};
+    use crate::year;
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days(year!(2025));
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -163,6 +182,26 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(12)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_of_older_events() {
+        let days: Vec<Day> = all_days(year!(2024)).collect();
+        assert_eq!(days.len(), 25);
+        assert_eq!(days.last(), Some(&Day(25)));
+    }
+
+    #[test]
+    fn parses_days_in_range() {
+        assert_eq!(Day::from_str_until("12", 12).unwrap(), Day(12));
+        assert_eq!(
+            Day::from_str_until("13", 12).unwrap_err().to_string(),
+            "expecting a day number between 1 and 12"
+        );
+        assert_eq!(
+            "26".parse::<Day>().unwrap_err().to_string(),
+            "expecting a day number between 1 and 25"
+        );
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,309 @@
+/// Module that reads the example manifest of a day, `data/<year>/examples/<day>.toml`. It lists the example files of
+/// the day with the part they apply to, the expected answer and the puzzle parameters they need, e.g.:
+///
+/// ```toml
+/// [[example]]
+/// file = "11-1.txt"
+/// part = 1
+/// answer = 5
+/// params.pairs = 10
+/// ```
+///
+/// The `solution!` macro generates a test that runs every case, so adding an example needs no code.
+use std::{fs, io, path::PathBuf};
+
+use toml::{Table, Value};
+
+use crate::template::{Day, IntoSolution, PuzzleParams, Year};
+
+/// A single example of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExampleCase {
+    /// Name of the file in the examples directory of the year. Defaults to `<day>.txt`.
+    pub file: String,
+    pub part: u8,
+    pub answer: String,
+    /// Parameters of the puzzle that differ between the example and the real input, as written in the manifest.
+    pub params: Vec<(String, String)>,
+}
+
+/// All examples of a day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExampleManifest {
+    pub cases: Vec<ExampleCase>,
+}
+
+impl ExampleManifest {
+    pub fn path(year: Year, day: Day) -> PathBuf {
+        year.data_dir().join("examples").join(format!("{day}.toml"))
+    }
+
+    /// Reads the manifest of a day. Days without a manifest have no cases.
+    pub fn read(year: Year, day: Day) -> Result<Self, String> {
+        let path = Self::path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                Self::from_toml(&contents, day).map_err(|e| format!("{}: {e}", path.display()))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    pub fn from_toml(contents: &str, day: Day) -> Result<Self, String> {
+        let mut table = contents.parse::<Table>().map_err(|e| e.to_string())?;
+
+        let examples = match table.remove("example") {
+            None => vec![],
+            Some(Value::Array(examples)) => examples,
+            Some(_) => return Err("`example` should be an array of tables.".into()),
+        };
+
+        if let Some(key) = table.keys().next() {
+            return Err(format!(
+                "unexpected setting `{key}`, expecting `[[example]]` tables."
+            ));
+        }
+
+        let cases = examples
+            .iter()
+            .enumerate()
+            .map(|(index, example)| {
+                example
+                    .as_table()
+                    .ok_or_else(|| "should be a table.".to_string())
+                    .and_then(|table| ExampleCase::try_from_table(table, day))
+                    .map_err(|e| format!("example {}: {e}", index + 1))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { cases })
+    }
+
+    /// Writes the manifest in the format that [`ExampleManifest::from_toml`] reads. Answers and parameters that are
+    /// integers are written as such, everything else as strings.
+    pub fn to_toml(&self, day: Day) -> String {
+        self.cases
+            .iter()
+            .map(|case| {
+                let mut lines = vec!["[[example]]".to_string()];
+                if case.file != format!("{day}.txt") {
+                    lines.push(format!("file = {}", Value::String(case.file.clone())));
+                }
+                lines.push(format!("part = {}", case.part));
+                lines.push(format!("answer = {}", to_value(&case.answer)));
+                for (name, value) in &case.params {
+                    lines.push(format!("params.{name} = {}", to_value(value)));
+                }
+                lines.join("\n") + "\n"
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Runs every case of a part against `func` with the parameters of the case and returns a description of each
+    /// failed case. Used by the test that `solution!` generates.
+    pub fn check<P: PuzzleParams, R: IntoSolution>(
+        &self,
+        year: Year,
+        part: u8,
+        func: impl Fn(&str, &P) -> R,
+    ) -> Vec<String> {
+        self.cases
+            .iter()
+            .filter(|case| case.part == part)
+            .filter_map(|case| {
+                let path = year.data_dir().join("examples").join(&case.file);
+                let input = match fs::read_to_string(&path) {
+                    Ok(input) => input,
+                    Err(e) => return Some(format!("{}: {e}", path.display())),
+                };
+
+                let params = match P::from_pairs(&case.params) {
+                    Ok(params) => params,
+                    Err(e) => return Some(format!("{}, part {part}: {e}", case.file)),
+                };
+
+                match func(&input, &params).into_solution() {
+                    Ok(answer) if answer.to_string() == case.answer => None,
+                    Ok(answer) => Some(format!(
+                        "{}, part {part}: expected {}, got {answer}",
+                        case.file, case.answer
+                    )),
+                    Err(e) => Some(format!(
+                        "{}, part {part}: expected {}, got {e}",
+                        case.file, case.answer
+                    )),
+                }
+            })
+            .collect()
+    }
+}
+
+impl ExampleCase {
+    fn try_from_table(table: &Table, day: Day) -> Result<Self, String> {
+        let file = match table.get("file") {
+            None => format!("{day}.txt"),
+            Some(Value::String(file)) => file.clone(),
+            Some(_) => return Err("`file` should be a string.".into()),
+        };
+
+        let part = table
+            .get("part")
+            .and_then(Value::as_integer)
+            .filter(|part| (1..=2).contains(part))
+            .ok_or("`part` should be 1 or 2.")?;
+
+        let answer = match table.get("answer") {
+            Some(Value::String(answer)) => answer.clone(),
+            Some(Value::Integer(answer)) => answer.to_string(),
+            _ => return Err("`answer` should be a string or an integer.".into()),
+        };
+
+        let params = match table.get("params") {
+            None => vec![],
+            Some(Value::Table(params)) => params
+                .iter()
+                .map(|(name, value)| {
+                    let value = match value {
+                        Value::String(value) => value.clone(),
+                        value => value.to_string(),
+                    };
+                    (name.clone(), value)
+                })
+                .collect(),
+            Some(_) => return Err("`params` should be a table.".into()),
+        };
+
+        if let Some(key) = table
+            .keys()
+            .find(|key| !["file", "part", "answer", "params"].contains(&key.as_str()))
+        {
+            return Err(format!("unexpected setting `{key}`."));
+        }
+
+        Ok(Self {
+            file,
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            part: part as u8,
+            answer,
+            params,
+        })
+    }
+}
+
+fn to_value(s: &str) -> Value {
+    s.parse()
+        .map_or_else(|_| Value::String(s.to_string()), Value::Integer)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{ExampleCase, ExampleManifest};
+    use crate::{day, year};
+
+    const MANIFEST: &str = r#"
+[[example]]
+part = 1
+answer = 40
+params.pairs = 10
+
+[[example]]
+file = "08-large.txt"
+part = 2
+answer = "25272"
+"#;
+
+    #[test]
+    fn reads_cases() {
+        let manifest = ExampleManifest::from_toml(MANIFEST, day!(8)).unwrap();
+
+        assert_eq!(
+            manifest.cases,
+            vec![
+                ExampleCase {
+                    file: "08.txt".into(),
+                    part: 1,
+                    answer: "40".into(),
+                    params: vec![("pairs".into(), "10".into())],
+                },
+                ExampleCase {
+                    file: "08-large.txt".into(),
+                    part: 2,
+                    answer: "25272".into(),
+                    params: vec![],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_cases() {
+        let manifest = ExampleManifest::from_toml(MANIFEST, day!(8)).unwrap();
+        let written = manifest.to_toml(day!(8));
+
+        assert_eq!(
+            written,
+            "[[example]]\npart = 1\nanswer = 40\nparams.pairs = 10\n\n[[example]]\nfile = \"08-large.txt\"\npart = 2\nanswer = 25272\n"
+        );
+        assert_eq!(
+            ExampleManifest::from_toml(&written, day!(8)).unwrap(),
+            manifest
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_cases() {
+        let error = |toml: &str| ExampleManifest::from_toml(toml, day!(1)).unwrap_err();
+
+        assert_eq!(
+            error("[[example]]\npart = 3\nanswer = 1"),
+            "example 1: `part` should be 1 or 2."
+        );
+        assert_eq!(
+            error("[[example]]\npart = 1"),
+            "example 1: `answer` should be a string or an integer."
+        );
+        assert!(
+            ExampleManifest::from_toml("[[example]]\npart = 1\nanswer = 1\nanwser = 2", day!(1))
+                .is_err()
+        );
+        assert!(ExampleManifest::from_toml("answer = 1", day!(1)).is_err());
+    }
+
+    #[test]
+    fn checks_cases_with_params() {
+        let manifest = ExampleManifest::from_toml(
+            "[[example]]\nfile = \"11-1.txt\"\npart = 1\nanswer = 5\nparams.steps = 3",
+            day!(11),
+        )
+        .unwrap();
+
+        let failures = manifest.check(year!(2025), 1, |_: &str, _: &()| Some(5));
+
+        assert_eq!(
+            failures,
+            vec!["11-1.txt, part 1: unknown parameter `steps`, this day has no parameters"]
+        );
+    }
+
+    #[test]
+    fn checks_cases() {
+        let manifest = ExampleManifest::from_toml(
+            "[[example]]\nfile = \"11-1.txt\"\npart = 1\nanswer = 5\n\n[[example]]\nfile = \"11-1.txt\"\npart = 1\nanswer = 6",
+            day!(11),
+        )
+        .unwrap();
+
+        let failures = manifest.check(year!(2025), 1, |_: &str, _: &()| Some(5));
+
+        assert_eq!(failures, vec!["11-1.txt, part 1: expected 6, got 5"]);
+        assert!(
+            manifest
+                .check(year!(2025), 2, |_: &str, _: &()| None::<u64>)
+                .is_empty()
+        );
+    }
+}
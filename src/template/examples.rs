@@ -0,0 +1,108 @@
+/// Module that finds the example and its expected answers in a puzzle description written by [`puzzle_to_markdown`].
+///
+/// [`puzzle_to_markdown`]: crate::template::markdown::puzzle_to_markdown
+const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+/// Code blocks with fewer lines and characters are unlikely to be an example input.
+const MIN_EXAMPLE_LINES: usize = 2;
+const MIN_EXAMPLE_CHARS: usize = 20;
+
+/// Finds the most likely example input: the first large code block after "For example".
+/// Falls back to the largest code block if there is no such block.
+pub fn extract_example(markdown: &str) -> Option<String> {
+    let blocks = code_blocks(markdown);
+
+    let after_example = markdown
+        .to_ascii_lowercase()
+        .find("for example")
+        .unwrap_or(0);
+
+    let is_large = |block: &str| {
+        block.lines().count() >= MIN_EXAMPLE_LINES || block.len() >= MIN_EXAMPLE_CHARS
+    };
+
+    blocks
+        .iter()
+        .find(|(pos, block)| *pos >= after_example && is_large(block))
+        .or_else(|| blocks.iter().max_by_key(|(_, block)| block.len()))
+        .map(|(_, block)| format!("{block}\n"))
+}
+
+/// Finds the expected answer of a part in its description, which is the last highlighted code, e.g. *`42`*.
+pub fn extract_answer(markdown: &str, part: u8) -> Option<String> {
+    let section = match (part, markdown.find(PART_TWO_HEADING)) {
+        (1, Some(pos)) => &markdown[..pos],
+        (1, None) => markdown,
+        (2, Some(pos)) => &markdown[pos..],
+        _ => return None,
+    };
+
+    section
+        .rmatch_indices("*`")
+        .find_map(|(start, _)| {
+            let content = &section[start + 2..];
+            let end = content.find("`*")?;
+            Some(content[..end].trim().to_string())
+        })
+        .filter(|answer| !answer.is_empty() && !answer.contains('\n'))
+}
+
+/// Returns all fenced code blocks with their position in the document.
+fn code_blocks(markdown: &str) -> Vec<(usize, String)> {
+    let mut blocks = vec![];
+    let mut current: Option<(usize, Vec<&str>)> = None;
+    let mut pos = 0;
+
+    for line in markdown.split_inclusive('\n') {
+        if line.trim_end() == "```" {
+            match current.take() {
+                Some((start, lines)) => blocks.push((start, lines.concat().trim_end().to_string())),
+                None => current = Some((pos, vec![])),
+            }
+        } else if let Some((_, lines)) = current.as_mut() {
+            lines.push(line);
+        }
+        pos += line.len();
+    }
+
+    blocks
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract_answer, extract_example};
+
+    const PART_ONE: &str = include_str!("fixtures/puzzle-part-1.md");
+    const BOTH_PARTS: &str = include_str!("fixtures/puzzle-both-parts.md");
+
+    #[test]
+    fn extracts_examples() {
+        assert_eq!(
+            extract_example(PART_ONE),
+            Some("3 1 2\n5 5\n4 < 6\n".into())
+        );
+    }
+
+    #[test]
+    fn skips_small_code_blocks() {
+        let markdown = "Numbers like this:\n\n```\n1\n```\n\nFor example:\n\n```\nx\n```\n\n```\n1 2\n3 4\n```\n";
+        assert_eq!(extract_example(markdown), Some("1 2\n3 4\n".into()));
+    }
+
+    #[test]
+    fn falls_back_to_largest_code_block() {
+        let markdown = "```\nab\n```\n\nFor example:\n\n```\nabc\n```\n";
+        assert_eq!(extract_example(markdown), Some("abc\n".into()));
+        assert_eq!(extract_example("No code."), None);
+    }
+
+    #[test]
+    fn extracts_answers() {
+        assert_eq!(extract_answer(PART_ONE, 1), Some("14".into()));
+        assert_eq!(extract_answer(PART_ONE, 2), None);
+        assert_eq!(extract_answer(BOTH_PARTS, 1), Some("14".into()));
+        assert_eq!(extract_answer(BOTH_PARTS, 2), Some("19".into()));
+    }
+}
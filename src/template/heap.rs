@@ -0,0 +1,254 @@
+/// Module that counts the heap allocations of solutions, so the runner can report the memory use of every part.
+///
+/// By default, a counting global allocator wraps the system allocator. It only counts while a part is measured, so it
+/// does not slow down the other runs. Builds with the `dhat-heap` feature use the allocator of `dhat` instead, and
+/// read its heap stats.
+use std::{collections::HashMap, fmt::Display};
+use tinyjson::JsonValue;
+
+/// Heap usage of a single execution of a part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HeapStats {
+    /// Bytes allocated in total, including memory that was freed again.
+    pub total_bytes: u64,
+    /// Most bytes that were allocated at the same time, not counting memory that was in use before the part started.
+    pub peak_bytes: u64,
+    pub allocations: u64,
+}
+
+impl HeapStats {
+    /// Adds the stats to a JSON object.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn insert_into(&self, map: &mut HashMap<String, JsonValue>) {
+        for (key, value) in [
+            ("heap_total_bytes", self.total_bytes),
+            ("heap_peak_bytes", self.peak_bytes),
+            ("heap_allocations", self.allocations),
+        ] {
+            map.insert(key.into(), JsonValue::Number(value as f64));
+        }
+    }
+
+    /// Reads the stats from a JSON object written by [`HeapStats::insert_into`]. Objects without heap stats, e.g.
+    /// timings that were stored before they were measured, have none.
+    pub fn from_json(json: &HashMap<String, JsonValue>) -> Result<Option<Self>, String> {
+        if !json.contains_key("heap_peak_bytes") {
+            return Ok(None);
+        }
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .filter(|v| *v >= 0_f64)
+                .map(|v| v as u64)
+                .ok_or_else(|| format!("Expected heap.{key} to be a positive number."))
+        };
+
+        Ok(Some(Self {
+            total_bytes: number("heap_total_bytes")?,
+            peak_bytes: number("heap_peak_bytes")?,
+            allocations: number("heap_allocations")?,
+        }))
+    }
+}
+
+impl Display for HeapStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.allocations == 0 {
+            return f.write_str("no allocations");
+        }
+
+        write!(
+            f,
+            "{} peak · {} in {} allocation{}",
+            format_bytes(self.peak_bytes),
+            format_bytes(self.total_bytes),
+            self.allocations,
+            if self.allocations == 1 { "" } else { "s" }
+        )
+    }
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `512 B` or `1.5 KiB`.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024_f64;
+    let mut unit = 0;
+    while value >= 1024_f64 && unit < UNITS.len() - 1 {
+        value /= 1024_f64;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(not(feature = "dhat-heap"))]
+pub use counting::{CountingAlloc, measure};
+
+#[cfg(not(feature = "dhat-heap"))]
+mod counting {
+    use super::HeapStats;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    // NOTE: the counters are only updated on the thread that runs `measure`, and only while it runs. Other
+    // allocations, e.g. the ones of `cargo time` samples, only pay for the check of `COUNTING`.
+    thread_local! {
+        static COUNTING: Cell<bool> = const { Cell::new(false) };
+        static TOTAL_BYTES: Cell<u64> = const { Cell::new(0) };
+        static CURRENT_BYTES: Cell<i64> = const { Cell::new(0) };
+        static PEAK_BYTES: Cell<i64> = const { Cell::new(0) };
+        static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    }
+
+    /// Global allocator that counts the allocations of the system allocator while [`measure`] runs.
+    pub struct CountingAlloc;
+
+    fn is_counting() -> bool {
+        COUNTING.try_with(Cell::get).unwrap_or(false)
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn record_alloc(size: usize) {
+        if !is_counting() {
+            return;
+        }
+        TOTAL_BYTES.set(TOTAL_BYTES.get() + size as u64);
+        ALLOCATIONS.set(ALLOCATIONS.get() + 1);
+        let current = CURRENT_BYTES.get() + size as i64;
+        CURRENT_BYTES.set(current);
+        PEAK_BYTES.set(PEAK_BYTES.get().max(current));
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn record_dealloc(size: usize) {
+        if !is_counting() {
+            return;
+        }
+        // NOTE: memory that was allocated before `measure` started can be freed, so this may drop below zero.
+        CURRENT_BYTES.set(CURRENT_BYTES.get() - size as i64);
+    }
+
+    unsafe impl GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            record_dealloc(layout.size());
+        }
+
+        // NOTE: a reallocation counts as a new allocation that frees the old one, like `dhat` counts them.
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                record_dealloc(layout.size());
+                record_alloc(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    /// Runs `func` and returns its result with the heap usage while it ran.
+    /// Only allocations of the current thread are counted, not the ones of threads that `func` spawns.
+    #[allow(clippy::cast_sign_loss)]
+    pub fn measure<T>(func: impl FnOnce() -> T) -> (T, HeapStats) {
+        TOTAL_BYTES.set(0);
+        CURRENT_BYTES.set(0);
+        PEAK_BYTES.set(0);
+        ALLOCATIONS.set(0);
+        COUNTING.set(true);
+
+        let result = func();
+
+        COUNTING.set(false);
+        let stats = HeapStats {
+            total_bytes: TOTAL_BYTES.get(),
+            peak_bytes: PEAK_BYTES.get() as u64,
+            allocations: ALLOCATIONS.get(),
+        };
+
+        (result, stats)
+    }
+}
+
+/// Runs `func` and returns its result with the heap usage while it ran, according to the running `dhat` profiler.
+#[cfg(feature = "dhat-heap")]
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, HeapStats) {
+    let start = dhat::HeapStats::get();
+    let result = func();
+    let end = dhat::HeapStats::get();
+
+    let stats = HeapStats {
+        total_bytes: end.total_bytes - start.total_bytes,
+        peak_bytes: end.max_bytes.saturating_sub(start.curr_bytes) as u64,
+        allocations: end.total_blocks - start.total_blocks,
+    };
+
+    (result, stats)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{HeapStats, format_bytes, measure};
+    use std::{collections::HashMap, hint::black_box};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn measures_allocations() {
+        let (_, stats) = measure(|| {
+            let first = black_box(vec![0_u8; 4096]);
+            drop(first);
+            black_box(vec![0_u8; 1024])
+        });
+
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.total_bytes, 5120);
+        assert_eq!(stats.peak_bytes, 4096);
+    }
+
+    #[test]
+    fn round_trips_heap_stats() {
+        let stats = HeapStats {
+            total_bytes: 5120,
+            peak_bytes: 4096,
+            allocations: 2,
+        };
+        let mut map = HashMap::new();
+        stats.insert_into(&mut map);
+
+        assert_eq!(HeapStats::from_json(&map), Ok(Some(stats)));
+        assert_eq!(HeapStats::from_json(&HashMap::new()), Ok(None));
+        assert_eq!(stats.to_string(), "4.0 KiB peak · 5.0 KiB in 2 allocations");
+    }
+}
@@ -0,0 +1,413 @@
+/// Module that keeps an append-only log of stored benchmarks.
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::runner::{PARSE_PART, part_label};
+use crate::template::timings::{Timing, Timings};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, Year};
+
+fn get_path(year: Year) -> PathBuf {
+    year.data_dir().join("timings-history.jsonl")
+}
+
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Benchmark times of a day, as stored by a single `cargo time --store`.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Commit that was checked out when the timings were stored. Missing outside of a git repository.
+    pub commit: Option<String>,
+    pub timing: Timing,
+}
+
+/// Append the timings to the history file of a year, one JSON object per line and day.
+pub fn append(year: Year, timings: &Timings) -> Result<(), io::Error> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let commit = read_commit_hash(Path::new(".git"));
+
+    fs::create_dir_all(year.data_dir())?;
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_path(year))?;
+
+    for timing in &timings.data {
+        let entry = HistoryEntry {
+            timestamp,
+            commit: commit.clone(),
+            timing: timing.clone(),
+        };
+        let line = JsonValue::from(&entry)
+            .stringify()
+            .map_err(|e| io::Error::other(e.to_string()))?;
+        writeln!(file, "{line}")?;
+    }
+
+    Ok(())
+}
+
+/// Read all entries of a day from the history file of a year, oldest first. Lines that can't be parsed are skipped.
+pub fn read_from_file(year: Year, day: Day) -> Vec<HistoryEntry> {
+    fs::read_to_string(get_path(year))
+        .map(|s| parse_entries(&s, day))
+        .unwrap_or_default()
+}
+
+fn parse_entries(s: &str, day: Day) -> Vec<HistoryEntry> {
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| JsonValue::from_str(line).ok())
+        .filter_map(|json| HistoryEntry::try_from(&json).ok())
+        .filter(|entry| entry.timing.day == day)
+        .collect()
+}
+
+/// Read the hash of the checked out commit from a git directory, without calling `git`.
+/// Supports detached heads, loose and packed refs, and `.git` files of worktrees.
+pub fn read_commit_hash(git_dir: &Path) -> Option<String> {
+    let git_dir = if git_dir.is_file() {
+        let content = fs::read_to_string(git_dir).ok()?;
+        let target = content.trim().strip_prefix("gitdir:")?.trim();
+        git_dir.parent()?.join(target)
+    } else {
+        git_dir.to_path_buf()
+    };
+
+    let head = fs::read_to_string(git_dir.join("HEAD")).ok()?;
+    let head = head.trim();
+
+    let Some(reference) = head.strip_prefix("ref:").map(str::trim) else {
+        return is_hash(head).then(|| head.to_string());
+    };
+
+    // NOTE: worktrees keep their refs in the common directory of the main repository.
+    let common_dir = fs::read_to_string(git_dir.join("commondir"))
+        .map(|s| git_dir.join(s.trim()))
+        .unwrap_or_else(|_| git_dir.clone());
+
+    [&git_dir, &common_dir]
+        .into_iter()
+        .find_map(|dir| fs::read_to_string(dir.join(reference)).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| is_hash(s))
+        .or_else(|| {
+            fs::read_to_string(common_dir.join("packed-refs"))
+                .ok()?
+                .lines()
+                .filter_map(|line| line.split_once(' '))
+                .find(|(_, name)| *name == reference)
+                .map(|(hash, _)| hash.to_string())
+                .filter(|s| is_hash(s))
+        })
+}
+
+fn is_hash(s: &str) -> bool {
+    s.len() >= 40 && s.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Print a table of all stored runs of a day, followed by a sparkline per part.
+pub fn print_history(day: Day, entries: &[HistoryEntry]) {
+    println!("{ANSI_BOLD}Day {day} history{ANSI_RESET}");
+    println!("------------------");
+
+    if entries.is_empty() {
+        println!("No stored benchmarks. Store some with `cargo time {day} --store`.");
+        return;
+    }
+
+    let parts = shown_parts(entries);
+    let header: Vec<String> = parts
+        .iter()
+        .map(|part| format!("{:<18}", part_label(*part)))
+        .collect();
+    println!("{:<16}  {:<7}  {}", "Date", "Commit", header.join("  "));
+
+    for (i, entry) in entries.iter().enumerate() {
+        let previous = i.checked_sub(1).map(|i| &entries[i].timing);
+        let commit = entry.commit.as_deref().map_or("-", |c| &c[..7]);
+        let cells: Vec<String> = parts
+            .iter()
+            .map(|part| format!("{:<18}", format_part(&entry.timing, previous, *part)))
+            .collect();
+        println!(
+            "{:<16}  {:<7}  {}",
+            format_timestamp(entry.timestamp),
+            commit,
+            cells.join("  ")
+        );
+    }
+
+    println!();
+    for part in parts {
+        let means: Vec<Duration> = entries
+            .iter()
+            .filter_map(|e| e.timing.part(part).filter(|p| !p.timed_out))
+            .map(|p| p.mean)
+            .collect();
+
+        if let (Some(first), Some(last)) = (means.first(), means.last()) {
+            println!(
+                "{}: {}  {ANSI_ITALIC}{first:.1?} → {last:.1?}{ANSI_RESET}",
+                part_label(part),
+                sparkline(&means)
+            );
+        }
+    }
+}
+
+/// The parts that have a column in the history. The parse column is only shown if a run of the day has it.
+fn shown_parts(entries: &[HistoryEntry]) -> Vec<u8> {
+    let has_parse = entries.iter().any(|e| e.timing.parse.is_some());
+    if has_parse {
+        vec![PARSE_PART, 1, 2]
+    } else {
+        vec![1, 2]
+    }
+}
+
+/// Format the mean of a part, with the change relative to the previous run.
+#[allow(clippy::cast_precision_loss)]
+fn format_part(timing: &Timing, previous: Option<&Timing>, part: u8) -> String {
+    let Some(current) = timing.part(part) else {
+        return "-".into();
+    };
+
+    // NOTE: parts that timed out have no duration to compare.
+    match previous.and_then(|t| t.part(part)) {
+        Some(old) if !old.mean.is_zero() && !old.timed_out && !current.timed_out => {
+            let old = old.mean.as_nanos() as f64;
+            let percent = (current.mean.as_nanos() as f64 - old) / old * 100_f64;
+            format!("{current} ({percent:+.1}%)")
+        }
+        _ => current.to_string(),
+    }
+}
+
+/// Render durations as a line of block characters, scaled between the fastest and the slowest one.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn sparkline(values: &[Duration]) -> String {
+    let min = values.iter().min().copied().unwrap_or_default();
+    let max = values.iter().max().copied().unwrap_or_default();
+    let range = (max - min).as_nanos() as f64;
+
+    values
+        .iter()
+        .map(|v| {
+            if range == 0_f64 {
+                return SPARKS[0];
+            }
+            let level = (*v - min).as_nanos() as f64 / range * (SPARKS.len() - 1) as f64;
+            SPARKS[level.round() as usize]
+        })
+        .collect()
+}
+
+/// Format seconds since the unix epoch as a UTC date and time, e.g. `2025-12-01 06:00`.
+fn format_timestamp(timestamp: u64) -> String {
+    let days = timestamp / 86_400;
+    let seconds = timestamp % 86_400;
+
+    // see: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map = match JsonValue::from(&value.timing) {
+            JsonValue::Object(map) => map,
+            _ => HashMap::new(),
+        };
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "commit".into(),
+            value
+                .commit
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>().copied())
+            .filter(|v| *v >= 0_f64)
+            .map(|v| v as u64)
+            .ok_or("Expected history.timestamp to be a positive number.")?;
+
+        let commit = json
+            .get("commit")
+            .and_then(|v| v.get::<String>())
+            .filter(|s| is_hash(s))
+            .cloned();
+
+        Ok(HistoryEntry {
+            timestamp,
+            commit,
+            timing: Timing::try_from(value)?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_timestamp, parse_entries, read_commit_hash, shown_parts, sparkline};
+    use crate::day;
+    use std::{
+        fs,
+        ops::Deref,
+        path::{Path, PathBuf},
+        time::Duration,
+    };
+
+    const HASH: &str = "fd6b5b7ae9622a32d12474e9e0ff322e8735c289";
+
+    /// A temporary git directory, removed when the test ends.
+    struct GitDir(PathBuf);
+
+    impl Deref for GitDir {
+        type Target = Path;
+
+        fn deref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for GitDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn git_dir(name: &str) -> GitDir {
+        let dir = std::env::temp_dir().join(format!("aoc-history-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("refs/heads")).unwrap();
+        GitDir(dir)
+    }
+
+    #[test]
+    fn parses_entries_of_a_day() {
+        let log = format!(
+            "{}\n{}\nnot json\n{}\n",
+            r#"{ "timestamp": 1764568800, "commit": null, "day": "01", "part_1": { "duration_nanos": 2000 }, "part_2": null, "total_nanos": 2000 }"#,
+            r#"{ "timestamp": 1764568900, "commit": "fd6b5b7ae9622a32d12474e9e0ff322e8735c289", "day": "02", "part_1": null, "part_2": null, "total_nanos": 0 }"#,
+            r#"{ "timestamp": 1764655200, "commit": "fd6b5b7ae9622a32d12474e9e0ff322e8735c289", "day": "01", "part_1": { "duration_nanos": 1000 }, "part_2": null, "total_nanos": 1000 }"#,
+        );
+
+        let entries = parse_entries(&log, day!(1));
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].commit, None);
+        assert_eq!(entries[1].commit.as_deref(), Some(HASH));
+        assert_eq!(
+            entries[1].timing.part(1).unwrap().mean,
+            Duration::from_nanos(1000)
+        );
+    }
+
+    #[test]
+    fn shows_parse_column_if_present() {
+        let log = format!(
+            "{}\n{}\n",
+            r#"{ "timestamp": 1764568800, "commit": null, "day": "01", "part_1": { "duration_nanos": 2000 }, "part_2": null, "total_nanos": 2000 }"#,
+            r#"{ "timestamp": 1764655200, "commit": null, "day": "01", "parse": { "duration_nanos": 500 }, "part_1": { "duration_nanos": 1000 }, "part_2": null, "total_nanos": 1500 }"#,
+        );
+
+        let entries = parse_entries(&log, day!(1));
+        assert_eq!(shown_parts(&entries), vec![0, 1, 2]);
+        assert_eq!(shown_parts(&entries[..1]), vec![1, 2]);
+    }
+
+    #[test]
+    fn reads_loose_refs() {
+        let dir = git_dir("loose");
+        fs::write(dir.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+        fs::write(dir.join("refs/heads/main"), format!("{HASH}\n")).unwrap();
+        assert_eq!(read_commit_hash(&dir).as_deref(), Some(HASH));
+    }
+
+    #[test]
+    fn reads_packed_refs_and_detached_heads() {
+        let dir = git_dir("packed");
+        fs::write(dir.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+        fs::write(
+            dir.join("packed-refs"),
+            format!("# pack-refs with: peeled fully-peeled sorted\n{HASH} refs/heads/main\n"),
+        )
+        .unwrap();
+        assert_eq!(read_commit_hash(&dir).as_deref(), Some(HASH));
+
+        fs::write(dir.join("HEAD"), format!("{HASH}\n")).unwrap();
+        assert_eq!(read_commit_hash(&dir).as_deref(), Some(HASH));
+    }
+
+    #[test]
+    fn handles_missing_repositories() {
+        let dir = git_dir("missing");
+        assert_eq!(read_commit_hash(&dir.join(".git")), None);
+    }
+
+    #[test]
+    fn renders_sparklines() {
+        let values = [4, 1, 8, 8].map(Duration::from_nanos);
+        assert_eq!(sparkline(&values), "▄▁██");
+        assert_eq!(sparkline(&[Duration::from_nanos(5); 3]), "▁▁▁");
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_764_568_800), "2025-12-01 06:00");
+        assert_eq!(format_timestamp(951_825_600), "2000-02-29 12:00");
+    }
+}
@@ -0,0 +1,472 @@
+/// Module that keeps a checksum manifest of the downloaded inputs, so empty, truncated or edited inputs are noticed
+/// before a solution runs against them. It also locates the inputs a solution can run against.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{self, Error, ErrorKind, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+fn get_path(year: Year) -> PathBuf {
+    year.data_dir().join("inputs.json")
+}
+
+/// Checksum and size of a downloaded input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputEntry {
+    pub day: Day,
+    pub checksum: String,
+    pub bytes: u64,
+}
+
+/// Checksums of all downloaded inputs.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub data: Vec<InputEntry>,
+}
+
+/// State of an input file compared with the manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputState {
+    Ok,
+    /// The input was not downloaded by `cargo download`, so it can't be checked.
+    Untracked,
+    /// The input is in the manifest, but the file is gone.
+    Missing,
+    Empty,
+    /// The input is shorter than the downloaded one.
+    Truncated {
+        bytes: u64,
+        expected: u64,
+    },
+    /// The input differs from the downloaded one.
+    Edited,
+}
+
+impl InputState {
+    pub fn is_problem(&self) -> bool {
+        !matches!(self, InputState::Ok | InputState::Untracked)
+    }
+}
+
+impl Display for InputState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputState::Ok => f.write_str("✅ ok"),
+            InputState::Untracked => f.write_str("❔ untracked"),
+            InputState::Missing => f.write_str("✖ missing"),
+            InputState::Empty => f.write_str("❌ empty"),
+            InputState::Truncated { bytes, expected } => {
+                write!(f, "❌ truncated ({bytes} of {expected} bytes)")
+            }
+            InputState::Edited => f.write_str("❌ edited"),
+        }
+    }
+}
+
+impl Manifest {
+    /// Dehydrate the manifest to the JSON file of a year.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        fs::create_dir_all(year.data_dir())?;
+        let mut file = fs::File::create(get_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the manifest from the JSON file of a year. If not present, returns an empty manifest.
+    /// A file that can't be read or parsed is an error, so its checksums are never dropped.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        Self::read_from_path(&get_path(year))
+    }
+
+    fn read_from_path(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                Manifest::try_from(contents).map_err(|e| format!("{}: {e}", path.display()))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    pub fn get(&self, day: Day) -> Option<&InputEntry> {
+        self.data.iter().find(|entry| entry.day == day)
+    }
+
+    /// Record the checksum of an input, replacing a previous one.
+    pub fn set(&mut self, day: Day, contents: &[u8]) {
+        self.data.retain(|entry| entry.day != day);
+        self.data.push(InputEntry {
+            day,
+            checksum: checksum(contents),
+            bytes: contents.len() as u64,
+        });
+        self.data.sort_unstable_by_key(|entry| entry.day);
+    }
+
+    /// Compare the contents of an input file with the manifest. `contents` is [`None`] if the file does not exist.
+    pub fn check(&self, day: Day, contents: Option<&[u8]>) -> InputState {
+        let entry = self.get(day);
+        let bytes = contents.map_or(0, |c| c.len() as u64);
+
+        match (contents, entry) {
+            (None, Some(_)) => InputState::Missing,
+            (None, None) => InputState::Untracked,
+            (Some([]), _) => InputState::Empty,
+            (Some(contents), Some(entry)) if checksum(contents) == entry.checksum => InputState::Ok,
+            (Some(_), Some(entry)) if bytes < entry.bytes => InputState::Truncated {
+                bytes,
+                expected: entry.bytes,
+            },
+            (Some(_), Some(_)) => InputState::Edited,
+            (Some(_), None) => InputState::Untracked,
+        }
+    }
+}
+
+/// Record the checksum of a downloaded input in the manifest of a year.
+/// A manifest that can't be parsed is left as is, instead of being replaced by this checksum.
+pub fn record(year: Year, day: Day, contents: &[u8]) -> Result<(), String> {
+    let mut manifest = Manifest::read_from_file(year)?;
+    manifest.set(day, contents);
+    manifest.store_file(year).map_err(|e| e.to_string())
+}
+
+/// 64-bit FNV-1a hash of the contents as hex. Good enough to notice changes, no need for a cryptographic hash.
+fn checksum(contents: &[u8]) -> String {
+    let hash = contents
+        .iter()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("{hash:016x}")
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The input a solution runs against.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InputSource {
+    /// The downloaded input, e.g. `data/2025/inputs/09.txt`.
+    #[default]
+    Default,
+    /// A named input next to the downloaded one, e.g. `data/2025/inputs/09.alice.txt` for `alice`.
+    Named(String),
+    /// Any file, e.g. a large synthetic input.
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Input from `--input <name>`. The name `default` selects the downloaded input.
+    pub fn named(name: &str) -> Self {
+        match name {
+            "default" => InputSource::Default,
+            name => InputSource::Named(name.to_string()),
+        }
+    }
+
+    /// Input from `--input-file <path>`. The path `-` reads from stdin.
+    pub fn file(path: &str) -> Self {
+        match path {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(path.into()),
+        }
+    }
+
+    pub fn is_default(&self) -> bool {
+        *self == InputSource::Default
+    }
+
+    /// Path of the input file, [`None`] for stdin.
+    pub fn path(&self, year: Year, day: Day) -> Option<PathBuf> {
+        let dir = year.data_dir().join("inputs");
+        match self {
+            InputSource::Default => Some(dir.join(format!("{day}.txt"))),
+            InputSource::Named(name) => Some(dir.join(format!("{day}.{name}.txt"))),
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, year: Year, day: Day) -> Result<String, Error> {
+        match self.path(year, day) {
+            Some(path) => fs::read_to_string(path),
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Arguments that pass this input on to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Default => vec![],
+            InputSource::Named(name) => vec!["--input".into(), name.clone()],
+            InputSource::File(path) => vec!["--input-file".into(), path.display().to_string()],
+            InputSource::Stdin => vec!["--input-file".into(), "-".into()],
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Default => f.write_str("default"),
+            InputSource::Named(name) => f.write_str(name),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => f.write_str("stdin"),
+        }
+    }
+}
+
+/// The default and named inputs of a day that exist in the inputs directory of a year, the default one first.
+pub fn available(year: Year, day: Day) -> Vec<InputSource> {
+    let Ok(entries) = fs::read_dir(year.data_dir().join("inputs")) else {
+        return vec![];
+    };
+
+    let mut inputs: Vec<InputSource> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| input_of_file(day, &entry.file_name().to_string_lossy()))
+        .collect();
+
+    inputs.sort();
+    inputs
+}
+
+/// Tells which input of a day a file in the inputs directory is, if any.
+fn input_of_file(day: Day, file_name: &str) -> Option<InputSource> {
+    let rest = file_name
+        .strip_prefix(&day.to_string())?
+        .strip_suffix(".txt")?;
+
+    match rest.strip_prefix('.') {
+        None if rest.is_empty() => Some(InputSource::Default),
+        Some(name) if !name.is_empty() => Some(InputSource::named(name)),
+        _ => None,
+    }
+}
+
+/// Matches an input name against a pattern where `*` stands for any text, e.g. `*`, `alice` or `big-*`.
+pub fn matches_pattern(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == name,
+        Some((prefix, rest)) => {
+            let Some(name) = name.strip_prefix(prefix) else {
+                return false;
+            };
+            // NOTE: let the wildcard take as few characters as needed for the rest of the pattern to match.
+            (0..=name.len())
+                .filter(|index| name.is_char_boundary(*index))
+                .any(|index| matches_pattern(rest, &name[index..]))
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Manifest> for JsonValue {
+    fn from(value: &Manifest) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Manifest {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Manifest {
+            data: json_data
+                .iter()
+                .map(InputEntry::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<&InputEntry> for JsonValue {
+    fn from(value: &InputEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("checksum".into(), JsonValue::String(value.checksum.clone()));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("bytes".into(), JsonValue::Number(value.bytes as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for InputEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected input to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected input.day to be a Day struct.")?;
+
+        let checksum = json
+            .get("checksum")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .ok_or("Expected input.checksum to be a string.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let bytes = json
+            .get("bytes")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u64)
+            .ok_or("Expected input.bytes to be a number.")?;
+
+        Ok(InputEntry {
+            day,
+            checksum,
+            bytes,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{InputSource, InputState, Manifest, checksum, input_of_file, matches_pattern};
+    use crate::day;
+    use std::fs;
+    use tinyjson::JsonValue;
+
+    const INPUT: &[u8] = b"1 2 3\n4 5 6\n";
+
+    #[test]
+    fn checks_inputs() {
+        let mut manifest = Manifest::default();
+        manifest.set(day!(1), INPUT);
+
+        assert_eq!(manifest.check(day!(1), Some(INPUT)), InputState::Ok);
+        assert_eq!(manifest.check(day!(1), None), InputState::Missing);
+        assert_eq!(manifest.check(day!(1), Some(b"")), InputState::Empty);
+        assert_eq!(
+            manifest.check(day!(1), Some(b"1 2 3\n")),
+            InputState::Truncated {
+                bytes: 6,
+                expected: 12
+            }
+        );
+        assert_eq!(
+            manifest.check(day!(1), Some(b"1 2 3\n4 5 7\n")),
+            InputState::Edited
+        );
+    }
+
+    #[test]
+    fn checks_untracked_inputs() {
+        let manifest = Manifest::default();
+
+        assert_eq!(manifest.check(day!(2), Some(INPUT)), InputState::Untracked);
+        assert_eq!(manifest.check(day!(2), None), InputState::Untracked);
+        assert_eq!(manifest.check(day!(2), Some(b"")), InputState::Empty);
+        assert!(!InputState::Untracked.is_problem());
+    }
+
+    #[test]
+    fn finds_inputs_of_a_day() {
+        assert_eq!(input_of_file(day!(9), "09.txt"), Some(InputSource::Default));
+        assert_eq!(
+            input_of_file(day!(9), "09.alice.txt"),
+            Some(InputSource::Named("alice".into()))
+        );
+        assert_eq!(input_of_file(day!(9), "10.txt"), None);
+        assert_eq!(input_of_file(day!(9), "09.txt.bak"), None);
+        assert_eq!(input_of_file(day!(9), "09..txt"), None);
+        assert_eq!(input_of_file(day!(9), "091.txt"), None);
+    }
+
+    #[test]
+    fn matches_input_names() {
+        assert!(matches_pattern("*", "default"));
+        assert!(matches_pattern("alice", "alice"));
+        assert!(!matches_pattern("alice", "bob"));
+        assert!(matches_pattern("big-*", "big-1"));
+        assert!(matches_pattern("*-large", "bob-large"));
+        assert!(!matches_pattern("big-*", "alice"));
+    }
+
+    #[test]
+    fn parses_input_arguments() {
+        assert_eq!(InputSource::named("default"), InputSource::Default);
+        assert_eq!(InputSource::file("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::named("alice").to_args(),
+            vec!["--input", "alice"]
+        );
+        assert!(InputSource::Default.to_args().is_empty());
+    }
+
+    #[test]
+    fn hashes_contents() {
+        assert_eq!(checksum(b""), "cbf29ce484222325");
+        assert_eq!(checksum(b"a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let mut manifest = Manifest::default();
+        manifest.set(day!(3), INPUT);
+        manifest.set(day!(1), b"x\n");
+        manifest.set(day!(3), b"y\n");
+
+        assert_eq!(manifest.data.len(), 2);
+        assert_eq!(manifest.data[0].day, day!(1));
+
+        let json = JsonValue::from(&manifest).stringify().unwrap();
+        assert_eq!(Manifest::try_from(json).unwrap(), manifest);
+    }
+
+    #[test]
+    fn reads_missing_manifests_as_empty() {
+        let path = std::env::temp_dir().join(format!("aoc-inputs-{}.json", std::process::id()));
+        assert_eq!(Manifest::read_from_path(&path), Ok(Manifest::default()));
+    }
+
+    #[test]
+    fn rejects_unparsable_manifests() {
+        let path = std::env::temp_dir().join(format!("aoc-inputs-bad-{}.json", std::process::id()));
+        fs::write(&path, "{ \"data\": [").unwrap();
+        let result = Manifest::read_from_path(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+}
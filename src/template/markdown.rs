@@ -0,0 +1,524 @@
+/// Module that converts puzzle descriptions from HTML to Markdown, and renders that Markdown in the terminal.
+use crate::template::aoc_client::{articles, decode_entities};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, config};
+
+/// Characters that are formatting in the Markdown written by this module, and escaped in text.
+const MARKDOWN_CHARS: [char; 6] = ['\\', '*', '_', '`', '[', ']'];
+
+/// Elements that never have children or a closing tag.
+const VOID_ELEMENTS: [&str; 6] = ["br", "hr", "img", "input", "meta", "link"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    Element {
+        name: String,
+        href: Option<String>,
+        children: Vec<Node>,
+    },
+    Text(String),
+}
+
+/// Converts the description of a puzzle page to Markdown.
+/// Every part is an `<article>` of its own, so part two is included once it is unlocked.
+pub fn puzzle_to_markdown(html: &str) -> String {
+    let parts: Vec<String> = articles(html)
+        .into_iter()
+        .map(|article| html_to_markdown(article).trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    let mut markdown = parts.join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+/// Converts an HTML fragment to Markdown.
+pub fn html_to_markdown(html: &str) -> String {
+    let nodes = parse(html);
+    let mut out = String::new();
+    write_blocks(&nodes, &mut out, 0);
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Parses HTML into a tree. Unknown closing tags are ignored, unclosed elements end with their parent.
+fn parse(html: &str) -> Vec<Node> {
+    // NOTE: the bottom of the stack collects the top-level nodes.
+    let mut stack: Vec<(String, Option<String>, Vec<Node>)> = vec![(String::new(), None, vec![])];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push_text(&mut stack, rest);
+            break;
+        };
+
+        push_text(&mut stack, &rest[..start]);
+
+        let Some(end) = rest[start..].find('>').map(|end| start + end) else {
+            push_text(&mut stack, &rest[start..]);
+            break;
+        };
+
+        let tag = &rest[start + 1..end];
+        rest = &rest[end + 1..];
+
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim().to_lowercase();
+            if let Some(index) = stack.iter().rposition(|(n, _, _)| *n == name)
+                && index > 0
+            {
+                while stack.len() > index {
+                    close_element(&mut stack);
+                }
+            }
+            continue;
+        }
+
+        let name: String = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        let href = attribute(tag, "href");
+
+        if tag.ends_with('/') || VOID_ELEMENTS.contains(&name.as_str()) {
+            stack.last_mut().unwrap().2.push(Node::Element {
+                name,
+                href,
+                children: vec![],
+            });
+        } else if name == "script" || name == "style" {
+            // skip the contents of elements that are not part of the description.
+            let closing = format!("</{name}>");
+            rest = rest
+                .find(&closing)
+                .map_or("", |i| &rest[i + closing.len()..]);
+        } else {
+            stack.push((name, href, vec![]));
+        }
+    }
+
+    while stack.len() > 1 {
+        close_element(&mut stack);
+    }
+
+    stack.pop().map(|(_, _, nodes)| nodes).unwrap_or_default()
+}
+
+fn push_text(stack: &mut [(String, Option<String>, Vec<Node>)], text: &str) {
+    if !text.is_empty() {
+        stack
+            .last_mut()
+            .unwrap()
+            .2
+            .push(Node::Text(decode_entities(text)));
+    }
+}
+
+fn close_element(stack: &mut Vec<(String, Option<String>, Vec<Node>)>) {
+    let (name, href, children) = stack.pop().unwrap();
+    stack.last_mut().unwrap().2.push(Node::Element {
+        name,
+        href,
+        children,
+    });
+}
+
+/// Reads a quoted attribute from the inside of a tag.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}="))? + name.len() + 1;
+    let quote = tag[start..].chars().next()?;
+    if quote != '"' && quote != '\'' {
+        return None;
+    }
+    let value = &tag[start + 1..];
+    let end = value.find(quote)?;
+    Some(decode_entities(&value[..end]))
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn write_blocks(nodes: &[Node], out: &mut String, depth: usize) {
+    let mut inline = String::new();
+
+    for node in nodes {
+        match node {
+            Node::Element { name, children, .. } if is_block(name) => {
+                flush_paragraph(&mut inline, out);
+                write_block(name, children, out, depth);
+            }
+            node => write_inline(node, &mut inline),
+        }
+    }
+
+    flush_paragraph(&mut inline, out);
+}
+
+fn is_block(name: &str) -> bool {
+    matches!(
+        name,
+        "p" | "pre" | "ul" | "ol" | "h1" | "h2" | "h3" | "h4" | "article" | "main" | "div" | "hr"
+    )
+}
+
+fn flush_paragraph(inline: &mut String, out: &mut String) {
+    let text = collapse_whitespace(inline);
+    if !text.is_empty() {
+        out.push_str(&text);
+        out.push_str("\n\n");
+    }
+    inline.clear();
+}
+
+fn write_block(name: &str, children: &[Node], out: &mut String, depth: usize) {
+    match name {
+        "h1" | "h2" | "h3" | "h4" => {
+            let level = name[1..].parse::<usize>().unwrap_or(2);
+            out.push_str(&"#".repeat(level));
+            out.push(' ');
+            out.push_str(&inline_markdown(children));
+            out.push_str("\n\n");
+        }
+        "pre" => {
+            out.push_str("```\n");
+            out.push_str(text_content(children).trim_end_matches('\n'));
+            out.push_str("\n```\n\n");
+        }
+        "ul" | "ol" => {
+            write_list(name == "ol", children, out, depth);
+            if depth == 0 {
+                out.push('\n');
+            }
+        }
+        "hr" => out.push_str("---\n\n"),
+        _ => write_blocks(children, out, depth),
+    }
+}
+
+fn write_list(ordered: bool, items: &[Node], out: &mut String, depth: usize) {
+    let indent = "  ".repeat(depth);
+    let items = items.iter().filter_map(|node| match node {
+        Node::Element { name, children, .. } if name == "li" => Some(children),
+        _ => None,
+    });
+
+    for (i, children) in items.enumerate() {
+        let marker = if ordered {
+            format!("{}.", i + 1)
+        } else {
+            "-".into()
+        };
+
+        let mut inline = String::new();
+        let mut nested = String::new();
+
+        for node in children {
+            match node {
+                Node::Element { name, children, .. } if name == "ul" || name == "ol" => {
+                    write_list(name == "ol", children, &mut nested, depth + 1);
+                }
+                Node::Element { name, children, .. } if name == "p" => {
+                    inline.push_str(&inline_markdown(children));
+                    inline.push(' ');
+                }
+                node => write_inline(node, &mut inline),
+            }
+        }
+
+        out.push_str(&format!(
+            "{indent}{marker} {}\n",
+            collapse_whitespace(&inline)
+        ));
+        out.push_str(&nested);
+    }
+}
+
+fn inline_markdown(nodes: &[Node]) -> String {
+    let mut out = String::new();
+    for node in nodes {
+        write_inline(node, &mut out);
+    }
+    collapse_whitespace(&out)
+}
+
+fn write_inline(node: &Node, out: &mut String) {
+    match node {
+        Node::Text(text) => out.push_str(&escape_markdown(text)),
+        Node::Element {
+            name,
+            href,
+            children,
+        } => match name.as_str() {
+            "em" | "strong" | "b" => {
+                let content = inline_markdown(children);
+                if !content.is_empty() {
+                    out.push_str(&format!("*{content}*"));
+                }
+            }
+            "code" => {
+                let content = text_content(children);
+                let code = if content.contains('`') {
+                    format!("`` {content} ``")
+                } else {
+                    format!("`{content}`")
+                };
+                // NOTE: highlighted code, e.g. the answer of an example.
+                if contains_element(children, "em") {
+                    out.push_str(&format!("*{code}*"));
+                } else {
+                    out.push_str(&code);
+                }
+            }
+            "a" => {
+                let content = inline_markdown(children);
+                match href {
+                    Some(href) => out.push_str(&format!("[{content}]({})", absolute_url(href))),
+                    None => out.push_str(&content),
+                }
+            }
+            "br" => out.push(' '),
+            _ => {
+                for child in children {
+                    write_inline(child, out);
+                }
+            }
+        },
+    }
+}
+
+fn text_content(nodes: &[Node]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node {
+            Node::Text(text) => text.clone(),
+            Node::Element { children, .. } => text_content(children),
+        })
+        .collect()
+}
+
+fn contains_element(nodes: &[Node], element: &str) -> bool {
+    nodes.iter().any(|node| match node {
+        Node::Element { name, children, .. } => {
+            name == element || contains_element(children, element)
+        }
+        Node::Text(_) => false,
+    })
+}
+
+/// Escapes the characters of text that would be read as formatting, e.g. `*` in `2*3`.
+fn escape_markdown(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if MARKDOWN_CHARS.contains(&c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Resolves links relative to the website, e.g. `/2025/about`, against the configured base url.
+fn absolute_url(href: &str) -> String {
+    if href.starts_with('/') {
+        format!("{}{href}", config().base_url.trim_end_matches('/'))
+    } else {
+        href.to_string()
+    }
+}
+
+/// Collapses runs of whitespace like a browser does.
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Renders Markdown written by [`puzzle_to_markdown`] with ANSI styles.
+/// Headings and emphasized text are bold, inline code is italic and code blocks are indented.
+pub fn render_ansi(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            out.push_str("    ");
+            out.push_str(line);
+        } else if let Some(heading) = line.strip_prefix('#') {
+            let heading = heading.trim_start_matches('#').trim();
+            out.push_str(&format!(
+                "{ANSI_BOLD}{}{ANSI_RESET}",
+                render_inline(heading)
+            ));
+        } else {
+            out.push_str(&render_inline(line));
+        }
+
+        out.push('\n');
+    }
+
+    out
+}
+
+fn render_inline(line: &str) -> String {
+    let mut out = String::new();
+    let mut rest = line;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '\\' => {
+                if let Some(escaped) = rest[1..]
+                    .chars()
+                    .next()
+                    .filter(|c| MARKDOWN_CHARS.contains(c))
+                {
+                    out.push(escaped);
+                    rest = &rest[1 + escaped.len_utf8()..];
+                    continue;
+                }
+            }
+            '*' => {
+                if let Some(end) = find_unescaped(&rest[1..], '*') {
+                    let content = render_inline(&rest[1..=end]);
+                    out.push_str(&format!("{ANSI_BOLD}{content}{ANSI_RESET}"));
+                    rest = &rest[end + 2..];
+                    continue;
+                }
+            }
+            '`' => {
+                if let Some(end) = rest[1..].find('`') {
+                    out.push_str(&format!("{ANSI_ITALIC}{}{ANSI_RESET}", &rest[1..=end]));
+                    rest = &rest[end + 2..];
+                    continue;
+                }
+            }
+            '[' => {
+                if let Some(text_end) = rest.find("](")
+                    && let Some(url_end) = rest[text_end..].find(')')
+                {
+                    out.push_str(&render_inline(&rest[1..text_end]));
+                    rest = &rest[text_end + url_end + 1..];
+                    continue;
+                }
+            }
+            _ => {}
+        }
+
+        out.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    out
+}
+
+/// Finds the first `target` in `s` that is not escaped with a backslash.
+fn find_unescaped(s: &str, target: char) -> Option<usize> {
+    let mut escaped = false;
+    for (index, c) in s.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == target => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{html_to_markdown, puzzle_to_markdown, render_ansi};
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    const PART_ONE: &str = include_str!("fixtures/puzzle-part-1.html");
+    const BOTH_PARTS: &str = include_str!("fixtures/puzzle-both-parts.html");
+
+    #[test]
+    fn converts_puzzle_fixtures() {
+        assert_eq!(
+            puzzle_to_markdown(PART_ONE),
+            include_str!("fixtures/puzzle-part-1.md")
+        );
+        assert_eq!(
+            puzzle_to_markdown(BOTH_PARTS),
+            include_str!("fixtures/puzzle-both-parts.md")
+        );
+    }
+
+    #[test]
+    fn appends_part_two_once_unlocked() {
+        let part_one = puzzle_to_markdown(PART_ONE);
+        let both_parts = puzzle_to_markdown(BOTH_PARTS);
+        assert!(both_parts.starts_with(part_one.trim_end()));
+        assert!(both_parts.contains("## --- Part Two ---"));
+    }
+
+    #[test]
+    fn converts_inline_elements() {
+        assert_eq!(
+            html_to_markdown(
+                r#"<p>The answer is <code><em>42</em></code>, <em>not</em> <code>x &lt; y</code>. See <a href="/2025/about">about</a>.</p>"#
+            ),
+            "The answer is *`42`*, *not* `x < y`. See [about](https://adventofcode.com/2025/about).\n\n"
+        );
+    }
+
+    #[test]
+    fn escapes_formatting_in_text() {
+        let markdown =
+            html_to_markdown("<p>Compute 2*3*4 for <em>x_1</em> in [a] \\ <code>a*b</code>.</p>");
+        assert_eq!(
+            markdown,
+            "Compute 2\\*3\\*4 for *x\\_1* in \\[a\\] \\\\ `a*b`.\n\n"
+        );
+        assert_eq!(
+            render_ansi(&markdown),
+            format!(
+                "Compute 2*3*4 for {ANSI_BOLD}x_1{ANSI_RESET} in [a] \\ {ANSI_ITALIC}a*b{ANSI_RESET}.\n\n"
+            )
+        );
+    }
+
+    #[test]
+    fn converts_nested_lists() {
+        assert_eq!(
+            html_to_markdown("<ul><li>one<ul><li>a</li><li>b</li></ul></li><li>two</li></ul>"),
+            "- one\n  - a\n  - b\n- two\n\n"
+        );
+        assert_eq!(
+            html_to_markdown("<ol><li>first</li><li>second</li></ol>"),
+            "1. first\n2. second\n\n"
+        );
+    }
+
+    #[test]
+    fn keeps_code_blocks_verbatim() {
+        assert_eq!(
+            html_to_markdown("<pre><code>..#\n#<em>.</em>.\n</code></pre>"),
+            "```\n..#\n#..\n```\n\n"
+        );
+    }
+
+    #[test]
+    fn renders_ansi() {
+        assert_eq!(
+            render_ansi("## Title\n\nAnswer *`42`* and `code`.\n\n```\n1 2\n```\n"),
+            format!(
+                "{ANSI_BOLD}Title{ANSI_RESET}\n\nAnswer {ANSI_BOLD}{ANSI_ITALIC}42{ANSI_RESET}{ANSI_RESET} and {ANSI_ITALIC}code{ANSI_RESET}.\n\n    1 2\n"
+            )
+        );
+    }
+}
@@ -1,68 +1,289 @@
 use std::{env, fs};
 
-pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
 pub mod runner;
 
+pub use config::{Config, ConfigError, config};
 pub use day::*;
+pub use example_manifest::ExampleManifest;
+pub use inputs::InputSource;
+pub use solution::*;
+pub use year::*;
 
+mod answers;
+mod config;
 mod day;
+mod example_manifest;
+mod examples;
+mod heap;
+mod history;
+mod inputs;
+mod markdown;
 mod readme_benchmarks;
+mod regression;
 mod run_multi;
+mod solution;
+mod stats;
+mod submissions;
 mod timings;
+mod year;
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+#[cfg(not(feature = "dhat-heap"))]
+#[global_allocator]
+static ALLOC: heap::CountingAlloc = heap::CountingAlloc;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file of a year to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join(year.data_dir())
+        .join(folder)
+        .join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+/// Helper function that reads a text file of a year to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(year.data_dir())
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
+///
+/// After the year and day, the macro takes these options, in any order:
+///  - `1` or `2` only runs a single part of the solution.
+///  - `both` runs `solve(input) -> (Option<A>, Option<B>)` instead of the parts, for days that find both answers in one
+///    pass. The parts are timed together.
+///  - `params = Params` passes the parameters of the puzzle, e.g. a number of steps that differs between the examples
+///    and the real input (see [`params!`](crate::params)). The parts then take `(input, params: &Params)`.
+///  - `parse = parse_input` runs `parse_input(input: &str)` once, before the parts, and times it separately. The parts
+///    then take a reference to its output instead of the input.
+///
+/// ```ignore
+/// advent_of_code::solution!(2025, 8, parse = parse_input, params = Params);
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// pub fn part_one(boxes: &[Point], params: &Params) -> Option<u64> { ... }
+/// ```
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:expr, $day:expr $(, $($options:tt)*)?) => {
+        $crate::solution!(@options [$year, $day] [[part_one, 1] [part_two, 2]] [] [] $($($options)*)?);
+    };
+
+    // NOTE: options are read one at a time into `[parts] [params type] [parse function]`.
+    (@options $head:tt $parts:tt $params:tt $parse:tt 1 $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $head [[part_one, 1]] $params $parse $($($rest)*)?);
+    };
+    (@options $head:tt $parts:tt $params:tt $parse:tt 2 $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $head [[part_two, 2]] $params $parse $($($rest)*)?);
+    };
+    (@options $head:tt $parts:tt $params:tt $parse:tt both $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $head [both] $params $parse $($($rest)*)?);
+    };
+    (@options $head:tt $parts:tt $params:tt $parse:tt params = $type:ty $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $head $parts [$type] $parse $($($rest)*)?);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    (@options $head:tt $parts:tt $params:tt $parse:tt parse = $func:expr $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $head $parts $params [$func] $($($rest)*)?);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    (@options $head:tt $parts:tt $params:tt $parse:tt) => {
+        $crate::solution!(@impl $head $parts $params $parse);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    // NOTE: parts without parameters are called like the ones with parameters, which are `()` for them.
+    (@params_type []) => { () };
+    (@params_type [$type:ty]) => { $type };
+    (@func $func:ident, []) => { (|input, _: &()| $func(input)) };
+    (@func $func:ident, [$type:ty]) => { $func };
+
+    // NOTE: parts take the input as is, or a reference to the output of the parse function.
+    (@arg [], $parsed:ident) => { $parsed };
+    (@arg [$parse:expr], $parsed:ident) => { &$parsed };
+    (@parse [], $input:expr, $options:expr, $reports:ident) => { $input };
+    (@parse [$parse:expr], $input:expr, $options:expr, $reports:ident) => {
+        match $crate::template::runner::run_parse($parse, $input, $options) {
+            (Some(parsed), report) => {
+                $reports.push(report);
+                parsed
+            }
+            (None, report) => return vec![report],
+        }
+    };
+    (@parse_example [], $input:expr) => { $input };
+    (@parse_example [$parse:expr], $input:expr) => { $parse($input) };
+
+    // NOTE: runs the parts, or `solve` for both parts, and checks them against the example manifest.
+    (@run [both], $params:tt, $data:ident, $values:ident, $options:ident, $reports:ident) => {
+        $reports.extend($crate::template::runner::run_both(
+            |data| $crate::solution!(@func solve, $params)(data, &$values),
+            $data,
+            YEAR,
+            DAY,
+            $options,
+        ));
+    };
+    (@run [$( [$func:ident, $part:expr] )*], $params:tt, $data:ident, $values:ident, $options:ident, $reports:ident) => {
+        $(
+            $reports.push($crate::template::runner::run_part(
+                |data| $crate::solution!(@func $func, $params)(data, &$values),
+                $data,
+                YEAR,
+                DAY,
+                $part,
+                $options,
+            ));
+        )*
+    };
+    (@check [both], $params:tt, $parse:tt, $manifest:ident, $failures:ident) => {
+        $crate::solution!(@check [[solve, 1, 0] [solve, 2, 1]], $params, $parse, $manifest, $failures);
+    };
+    (@check [$( [$func:ident, $part:expr $(, $index:tt)?] )*], $params:tt, $parse:tt, $manifest:ident, $failures:ident) => {
+        $(
+            $failures.extend($manifest.check(
+                YEAR,
+                $part,
+                |input: &str, params: &$crate::solution!(@params_type $params)| {
+                    let parsed = $crate::solution!(@parse_example $parse, input);
+                    $crate::solution!(@func $func, $params)($crate::solution!(@arg $parse, parsed), params)$(.$index)?
+                },
+            ));
+        )*
+    };
+
+    (@impl [$year:expr, $day:expr] $parts:tt $params:tt $parse:tt) => {
+        /// The year of the puzzle.
+        pub const YEAR: $crate::template::Year = $crate::year!($year);
+
         /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        pub const DAY: $crate::template::Day = $crate::day!($day);
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+        /// Runs the solution against the input selected in the options, the real input by default.
+        pub fn run(
+            options: &$crate::template::runner::RunOptions,
+        ) -> Vec<$crate::template::runner::PartReport> {
+            let input = options
+                .input
+                .read(YEAR, DAY)
+                .unwrap_or_else(|e| panic!("could not read the {} input: {e}", options.input));
+            let params = <$crate::solution!(@params_type $params) as $crate::template::PuzzleParams>::from_pairs(&options.params)
+                .unwrap_or_else(|e| {
+                    eprintln!("Invalid parameters: {e}.");
+                    std::process::exit(1);
+                });
+            let mut reports = vec![];
+            let parsed = $crate::solution!(@parse $parse, input.as_str(), options, reports);
+            let data = $crate::solution!(@arg $parse, parsed);
+            $crate::solution!(@run $parts, $params, data, params, options, reports);
+            reports
+        }
 
+        /// Runs every case of the example manifest of this day, see [`ExampleManifest`]($crate::template::ExampleManifest).
+        #[cfg(test)]
+        #[test]
+        fn example_manifest() {
+            let manifest = $crate::template::ExampleManifest::read(YEAR, DAY).unwrap();
+            let mut failures: Vec<String> = vec![];
+            $crate::solution!(@check $parts, $params, $parse, manifest, failures);
+            assert!(failures.is_empty(), "failed examples:\n{}", failures.join("\n"));
+        }
+
+        // NOTE: unused when the solution is compiled into the main binary with `days!`.
+        #[allow(dead_code)]
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            run(&$crate::template::runner::RunOptions::from_args());
+        }
+    };
+}
+
+/// Creates the `Params` struct of a day with the given fields and their default values, which are the ones of the real
+/// input. Every field can be set with `--param <name>=<value>` or in the example manifest, e.g. `params.pairs = 10`.
+///
+/// ```ignore
+/// advent_of_code::params! {
+///     /// Number of pairs to connect.
+///     pairs: usize = 1000,
+/// }
+/// ```
+#[macro_export]
+macro_rules! params {
+    ($( $(#[$meta:meta])* $name:ident: $ty:ty = $default:expr ),* $(,)?) => {
+        /// Parameters of the puzzle that differ between the examples and the real input.
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct Params {
+            $( $(#[$meta])* pub $name: $ty, )*
+        }
+
+        impl Default for Params {
+            fn default() -> Self {
+                Self {
+                    $( $name: $default, )*
+                }
+            }
+        }
+
+        impl $crate::template::PuzzleParams for Params {
+            fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+                match key {
+                    $(
+                        stringify!($name) => {
+                            self.$name = value
+                                .parse()
+                                .map_err(|e| format!("invalid value of `{key}`: {e}"))?;
+                        }
+                    )*
+                    _ => return Err(format!("unknown parameter `{key}`")),
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Compiles the given solution binaries into the current crate and creates a `registry()` function listing them,
+/// so they can be run in-process.
+///
+/// Solution modules are left out of test builds, as their tests already run as part of their own binary.
+#[macro_export]
+macro_rules! days {
+    ($( $module:ident => $path:literal ),* $(,)?) => {
+        $(
+            #[cfg(not(test))]
+            #[path = $path]
+            mod $module;
+        )*
+
+        /// Solutions that are compiled into this binary.
+        fn registry() -> Vec<$crate::template::runner::DaySolution> {
+            #[cfg(not(test))]
+            let registry = vec![$(
+                $crate::template::runner::DaySolution {
+                    year: $module::YEAR,
+                    day: $module::DAY,
+                    run: $module::run,
+                },
+            )*];
+
+            #[cfg(test)]
+            let registry = vec![];
+
+            registry
         }
     };
 }
@@ -2,10 +2,15 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
-use crate::template::timings::Timings;
+use crate::template::heap::format_bytes;
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::timings::{PartTiming, Timing, Timings};
+use crate::template::{Year, config};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+/// Every year has its own table between two of its markers.
+fn marker(year: Year) -> String {
+    format!("<!--- {} {year} --->", config().benchmark_marker)
+}
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -25,13 +30,8 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -52,75 +52,134 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(prefix: &str, year: Year, timings: Timings, total_millis: f64) -> String {
+    let header = format!("{prefix} {year} Benchmarks");
+
+    // NOTE: the parse and memory columns are only shown if a day has them, so other tables stay the same.
+    let has_parse = timings.data.iter().any(|t| t.parse.is_some());
+    let has_memory = timings.data.iter().any(|t| peak_bytes(t).is_some());
+
+    let mut columns = vec!["Day"];
+    if has_parse {
+        columns.push("Parse");
+    }
+    columns.extend(["Part 1", "Part 2"]);
+    if has_memory {
+        columns.push("Memory");
+    }
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker(year),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| {} |", columns.join(" | ")),
+        format!("| {}  |", vec![":---:"; columns.len()].join(" | ")),
     ];
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        let path = get_path_for_bin(year, timing.day);
+        let format_part = |part: &Option<PartTiming>| {
+            part.as_ref()
+                .map_or_else(|| "-".into(), ToString::to_string)
+        };
+
+        let mut cells = vec![format!("[Day {}]({})", timing.day.into_inner(), path)];
+        if has_parse {
+            cells.push(format!("`{}`", format_part(&timing.parse)));
+        }
+        cells.push(format!("`{}`", format_part(&timing.part_1)));
+        // NOTE: days that solve both parts in one pass show that pass as part 1.
+        cells.push(if timing.combined {
+            "combined".into()
+        } else {
+            format!("`{}`", format_part(&timing.part_2))
+        });
+        if has_memory {
+            cells.push(
+                peak_bytes(&timing)
+                    .map_or_else(|| "-".into(), |b| format!("`{}`", format_bytes(b))),
+            );
+        }
+
+        lines.push(format!("| {} |", cells.join(" | ")));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker(year));
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+/// Largest peak heap usage of the parse phase and the parts of a day, if it was measured.
+fn peak_bytes(timing: &Timing) -> Option<u64> {
+    [&timing.parse, &timing.part_1, &timing.part_2]
+        .into_iter()
+        .filter_map(|part| part.as_ref().and_then(|p| p.heap))
+        .map(|heap| heap.peak_bytes)
+        .max()
+}
+
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let positions = locate_table(s, &marker(year))?;
+    let table = construct_table("##", year, timings, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
+/// Updates the benchmarks table of a year in the readme.
+pub fn update(year: Year, timings: Timings) -> Result<(), Error> {
+    let path = &config().readme;
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{marker, update_content};
+    use crate::{
+        day,
+        template::{
+            heap::HeapStats,
+            timings::{PartTiming, Timing, Timings},
+        },
+        year,
+    };
+    use std::time::Duration;
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse: None,
+                    part_1: Some(PartTiming::new(Duration::from_millis(10))),
+                    part_2: Some(PartTiming::new(Duration::from_millis(20))),
+                    combined: false,
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse: None,
+                    part_1: Some(PartTiming::new(Duration::from_millis(30))),
+                    part_2: Some(PartTiming::new(Duration::from_millis(40))),
+                    combined: false,
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    parse: None,
+                    part_1: Some(PartTiming::new(Duration::from_millis(40))),
+                    part_2: Some(PartTiming::new(Duration::from_millis(50))),
+                    combined: false,
                     total_nanos: 9e+10,
                 },
             ],
@@ -131,53 +190,121 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
-        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = marker(year!(2025));
+        let mut s = format!("{} {} {}", marker, marker, marker);
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        let marker = marker(year!(2025));
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker, marker);
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.contains("## 2025 Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        let marker = marker(year!(2025));
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker, marker);
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.matches(&marker).collect::<Vec<&str>>().len(), 2);
+        assert_eq!(
+            s.matches("## 2025 Benchmarks").collect::<Vec<&str>>().len(),
+            1
+        );
     }
 
     #[test]
     fn format_benchmarks() {
-        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = marker(year!(2025));
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", marker, marker);
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2025 --->",
+            "## 2025 Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2025-01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2025-02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2025-04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table 2025 --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn formats_parse_column() {
+        let marker = marker(year!(2025));
+        let mut s = format!("{marker}{marker}");
+        let mut timings = get_mock_timings();
+        timings.data[1].parse = Some(PartTiming::new(Duration::from_millis(5)));
+        update_content(&mut s, year!(2025), timings, 195.0).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [Day 1](./src/bin/2025-01.rs) | `-` | `10.0ms` | `20.0ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2025-02.rs) | `5.0ms` | `30.0ms` | `40.0ms` |"));
+    }
+
+    #[test]
+    fn marks_combined_days() {
+        let marker = marker(year!(2025));
+        let mut s = format!("{marker}{marker}");
+        let mut timings = get_mock_timings();
+        timings.data[0].part_2 = None;
+        timings.data[0].combined = true;
+        update_content(&mut s, year!(2025), timings, 170.0).unwrap();
+        assert!(s.contains("| [Day 1](./src/bin/2025-01.rs) | `10.0ms` | combined |"));
+    }
+
+    #[test]
+    fn formats_memory_column() {
+        let marker = marker(year!(2025));
+        let mut s = format!("{marker}{marker}");
+        let mut timings = get_mock_timings();
+        let heap = |peak_bytes| {
+            Some(HeapStats {
+                total_bytes: 4 * peak_bytes,
+                peak_bytes,
+                allocations: 10,
+            })
+        };
+        timings.data[0].part_1 = timings.data[0]
+            .part_1
+            .clone()
+            .map(|p| p.with_heap(heap(512)));
+        timings.data[0].part_2 = timings.data[0]
+            .part_2
+            .clone()
+            .map(|p| p.with_heap(heap(2048)));
+        update_content(&mut s, year!(2025), timings, 190.0).unwrap();
+        assert!(
+            s.contains("| Day | Part 1 | Part 2 | Memory |\n| :---: | :---: | :---: | :---:  |")
+        );
+        assert!(s.contains("| [Day 1](./src/bin/2025-01.rs) | `10.0ms` | `20.0ms` | `2.0 KiB` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2025-02.rs) | `30.0ms` | `40.0ms` | - |"));
+    }
+
+    #[test]
+    fn keeps_tables_of_other_years() {
+        let (m2024, m2025) = (marker(year!(2024)), marker(year!(2025)));
+        let mut s = format!("{m2024}\nold\n{m2024}\n{m2025}{m2025}");
+        update_content(&mut s, year!(2025), get_mock_timings(), 190.0).unwrap();
+        assert!(s.starts_with(&format!(
+            "{m2024}\nold\n{m2024}\n{m2025}\n## 2025 Benchmarks"
+        )));
+    }
 }
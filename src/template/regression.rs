@@ -0,0 +1,257 @@
+/// Module that compares fresh benchmarks against the stored baseline.
+use std::{fmt::Display, time::Duration};
+
+use crate::template::runner::{PARSE_PART, part_label};
+use crate::template::timings::{PartTiming, Timings};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day};
+
+/// Differences within this many baseline standard deviations are considered noise.
+const NOISE_STDDEVS: f64 = 2.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change {
+    Faster,
+    Slower,
+    Unchanged,
+    /// The part timed out, so it is at least as slow as the timeout.
+    TimedOut,
+    /// Only the baseline timed out, so there is no duration to compare against.
+    Incomparable,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Faster => f.write_str("🟢 faster"),
+            Change::Slower => f.write_str("🔴 slower"),
+            Change::Unchanged => f.write_str("⚪ unchanged"),
+            Change::TimedOut => f.write_str("⏱ timed out"),
+            Change::Incomparable => f.write_str("❔ baseline timed out"),
+        }
+    }
+}
+
+/// Comparison of a single part against its baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartComparison {
+    pub day: Day,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+    pub change: Change,
+    /// Relative change of the mean in percent. Positive values are slowdowns.
+    pub percent: f64,
+}
+
+impl PartComparison {
+    /// Whether the part timed out or got slower by more than `max_regression` percent.
+    pub fn is_regression(&self, max_regression: f64) -> bool {
+        match self.change {
+            Change::TimedOut => true,
+            Change::Slower => self.percent > max_regression,
+            _ => false,
+        }
+    }
+}
+
+/// Compare the mean of a part against the baseline.
+/// The noise threshold is derived from the spread recorded for both measurements.
+/// Baselines that were migrated from preformatted durations have no recorded spread.
+/// Parts that timed out have no mean to compare, see [`Change::TimedOut`] and [`Change::Incomparable`].
+#[allow(clippy::cast_precision_loss)]
+fn compare_parts(baseline: &PartTiming, current: &PartTiming) -> (Change, f64) {
+    if current.timed_out {
+        return (Change::TimedOut, 0_f64);
+    }
+    if baseline.timed_out {
+        return (Change::Incomparable, 0_f64);
+    }
+
+    let old = baseline.mean.as_nanos() as f64;
+    let new = current.mean.as_nanos() as f64;
+    let delta = new - old;
+
+    let stddev = [&baseline.stats, &current.stats]
+        .into_iter()
+        .flatten()
+        .map(|s| s.stddev)
+        .max()
+        .unwrap_or_default();
+    let noise = NOISE_STDDEVS * stddev.as_nanos() as f64;
+
+    let percent = if old > 0_f64 {
+        delta / old * 100_f64
+    } else {
+        0_f64
+    };
+
+    let change = if delta.abs() <= noise {
+        Change::Unchanged
+    } else if delta > 0_f64 {
+        Change::Slower
+    } else {
+        Change::Faster
+    };
+
+    (change, percent)
+}
+
+/// Compare every benched part in `current` that has a baseline.
+pub fn compare(baseline: &Timings, current: &Timings) -> Vec<PartComparison> {
+    let mut comparisons = vec![];
+
+    for timing in &current.data {
+        let Some(stored) = baseline.data.iter().find(|t| t.day == timing.day) else {
+            continue;
+        };
+
+        for part in [PARSE_PART, 1, 2] {
+            if let (Some(old), Some(new)) = (stored.part(part), timing.part(part)) {
+                let (change, percent) = compare_parts(old, new);
+                comparisons.push(PartComparison {
+                    day: timing.day,
+                    part,
+                    baseline: old.mean,
+                    current: new.mean,
+                    change,
+                    percent,
+                });
+            }
+        }
+    }
+
+    comparisons
+}
+
+pub fn print_comparisons(comparisons: &[PartComparison], max_regression: f64) {
+    println!("\n{ANSI_BOLD}Comparison with stored benchmarks:{ANSI_RESET}");
+
+    if comparisons.is_empty() {
+        println!("No stored benchmarks to compare against.");
+        return;
+    }
+
+    for c in comparisons {
+        let marker = if c.is_regression(max_regression) {
+            " ‼"
+        } else {
+            ""
+        };
+        let percent = match c.change {
+            Change::TimedOut | Change::Incomparable => String::new(),
+            _ => format!(" ({:+.1}%)", c.percent),
+        };
+        println!(
+            "Day {} {}: {:.1?} → {:.1?}{percent} {}{marker}",
+            c.day,
+            part_label(c.part),
+            c.baseline,
+            c.current,
+            c.change
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Change, compare};
+    use crate::{
+        day,
+        template::{
+            stats::Stats,
+            timings::{PartTiming, Timing, Timings},
+        },
+    };
+    use std::time::Duration;
+
+    fn stats(mean: u64, stddev: u64) -> Stats {
+        Stats {
+            stddev: Duration::from_nanos(stddev),
+            samples: 100,
+            ..Stats::single(Duration::from_nanos(mean))
+        }
+    }
+
+    fn timings(part_1: Option<Stats>, part_2: Option<Stats>) -> Timings {
+        Timings {
+            data: vec![Timing {
+                day: day!(1),
+                parse: None,
+                part_1: part_1.map(PartTiming::from_stats),
+                part_2: part_2.map(PartTiming::from_stats),
+                combined: false,
+                total_nanos: 0_f64,
+            }],
+        }
+    }
+
+    #[test]
+    fn detects_changes() {
+        let baseline = timings(Some(stats(1000, 10)), Some(stats(1000, 10)));
+        let current = timings(Some(stats(1200, 10)), Some(stats(800, 10)));
+        let comparisons = compare(&baseline, &current);
+
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].change, Change::Slower);
+        assert_eq!(comparisons[0].percent, 20_f64);
+        assert!(comparisons[0].is_regression(10_f64));
+        assert!(!comparisons[0].is_regression(25_f64));
+        assert_eq!(comparisons[1].change, Change::Faster);
+        assert!(!comparisons[1].is_regression(10_f64));
+    }
+
+    #[test]
+    fn ignores_changes_within_noise() {
+        let baseline = timings(Some(stats(1000, 100)), None);
+        let current = timings(Some(stats(1150, 10)), None);
+        let comparisons = compare(&baseline, &current);
+
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].change, Change::Unchanged);
+        assert!(!comparisons[0].is_regression(10_f64));
+    }
+
+    #[test]
+    fn compares_migrated_baselines() {
+        let mut baseline = timings(None, None);
+        baseline.data[0].part_1 = Some(PartTiming::new(Duration::from_nanos(1000)));
+        let current = timings(Some(stats(1150, 10)), None);
+        let comparisons = compare(&baseline, &current);
+
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].change, Change::Slower);
+    }
+
+    #[test]
+    fn reports_timed_out_parts_as_regressions() {
+        let baseline = timings(Some(stats(1000, 10)), None);
+        let mut current = timings(None, None);
+        current.data[0].part_1 = Some(PartTiming::timed_out(Duration::from_secs(1)));
+        let comparisons = compare(&baseline, &current);
+
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].change, Change::TimedOut);
+        assert!(comparisons[0].is_regression(10_f64));
+    }
+
+    #[test]
+    fn does_not_compare_against_timed_out_baselines() {
+        let mut baseline = timings(None, None);
+        baseline.data[0].part_1 = Some(PartTiming::timed_out(Duration::from_secs(1)));
+        let current = timings(Some(stats(1000, 10)), None);
+        let comparisons = compare(&baseline, &current);
+
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].change, Change::Incomparable);
+        assert!(!comparisons[0].is_regression(10_f64));
+    }
+
+    #[test]
+    fn skips_parts_without_baseline() {
+        let baseline = timings(None, Some(stats(1000, 10)));
+        let current = timings(Some(stats(1000, 10)), None);
+        assert!(compare(&baseline, &current).is_empty());
+    }
+}
@@ -1,19 +1,39 @@
-use std::{collections::HashSet, io};
+use std::{collections::HashSet, fmt::Display, io, time::Duration};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::runner::{BenchOptions, DaySolution, PARSE_PART, PartReport, part_label};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, InputSource, PartStatus, Year};
 
 use super::{
     all_days,
-    timings::{Timing, Timings},
+    timings::{PartTiming, Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Runs the given days of a year and prints their output.
+///
+/// Days of the year in the `registry` run in the current process, unless `is_isolated` is set.
+/// All other days, e.g. ones that were scaffolded after the current binary was built, run their own binary.
+/// Passing `bench` options benches all parts and returns their timings.
+/// Passing a `timeout` reports parts that run longer as timed out. A part that runs in the current process can't be
+/// stopped, so every day runs its own binary then, which ends when a part times out. The parts after it are reported as
+/// not run. A binary that runs longer than [`run_limit`] is killed.
+/// Every day runs against the given `input`.
+#[allow(clippy::too_many_arguments)]
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    registry: &[DaySolution],
+    is_release: bool,
+    bench: Option<&BenchOptions>,
+    timeout: Option<Duration>,
+    is_isolated: bool,
+    input: &InputSource,
+) -> MultiRun {
+    let mut days: Vec<DayRun> = Vec::with_capacity(days_to_run.len());
 
     let mut need_space = false;
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    all_days(year)
         .filter(|day| days_to_run.contains(day))
         .for_each(|day| {
             if need_space {
@@ -24,25 +44,88 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let solution = registry.iter().find(|s| s.year == year && s.day == day);
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
+            days.push(match solution {
+                Some(solution) if !is_isolated && timeout.is_none() => {
+                    in_process::run_solution(solution, bench, input)
+                }
+                _ => run_child(year, day, bench, timeout, is_release, input),
+            });
         });
 
-    if is_timed {
-        let timings = Timings { data: timings };
+    if days.iter().any(|d| !d.failures.is_empty()) {
+        println!("\n{ANSI_BOLD}Failures:{ANSI_RESET}");
+        for run in &days {
+            for failure in &run.failures {
+                println!("Day {}: {failure}", run.day);
+            }
+        }
+    }
+
+    let timings = bench.map(|_| {
+        let timings = Timings {
+            data: days
+                .iter()
+                .filter(|d| !d.reports.is_empty())
+                .map(|d| timing_from_reports(&d.reports, d.day))
+                .collect(),
+        };
         let total_millis = timings.total_millis();
         println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            "\n{ANSI_BOLD}Total {year} (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
+        timings
+    });
+
+    MultiRun { days, timings }
+}
+
+/// Outcome of running a set of days.
+pub struct MultiRun {
+    pub days: Vec<DayRun>,
+    /// Timings of benched parts. Only present when benching.
+    pub timings: Option<Timings>,
+}
+
+/// Outcome of running a single day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayRun {
+    pub day: Day,
+    pub reports: Vec<PartReport>,
+    pub failures: Vec<DayFailure>,
+}
+
+impl DayRun {
+    /// The report of a part, if the part ran.
+    pub fn report(&self, part: u8) -> Option<&PartReport> {
+        self.reports.iter().find(|r| r.part == part)
+    }
+}
+
+/// Reason why a day did not produce an answer for every part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayFailure {
+    /// There is no solution binary for the day yet.
+    NotScaffolded,
+    /// The solution binary failed to build or exited with an error.
+    Crashed(String),
+    /// A part, or the parse phase, ran, but did not produce an answer.
+    Part(u8, PartStatus),
+    /// A part did not run, because an earlier part timed out and ended the binary.
+    NotRun(u8),
+}
+
+impl Display for DayFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DayFailure::NotScaffolded => f.write_str("not scaffolded"),
+            DayFailure::Crashed(status) => write!(f, "failed to run ({status})"),
+            DayFailure::Part(part, status) => {
+                write!(f, "{} {status}", part_label(*part).to_lowercase())
+            }
+            DayFailure::NotRun(part) => write!(f, "{} not run", part_label(*part).to_lowercase()),
+        }
     }
 }
 
@@ -59,45 +142,258 @@ impl From<std::io::Error> for Error {
     }
 }
 
+/// Name of the solution binary of a day, e.g. `2025-01`.
+#[must_use]
+pub fn get_bin_name(year: Year, day: Day) -> String {
+    format!("{year}-{day}")
+}
+
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{}.rs", get_bin_name(year, day))
+}
+
+fn run_child(
+    year: Year,
+    day: Day,
+    bench: Option<&BenchOptions>,
+    timeout: Option<Duration>,
+    is_release: bool,
+    input: &InputSource,
+) -> DayRun {
+    let run = child_commands::run_solution(year, day, bench, timeout, is_release, input).unwrap();
+    let failures = child_commands::parse_failures(&run);
+
+    let reports = match run {
+        child_commands::SolutionRun::NotScaffolded => {
+            println!("Not scaffolded.");
+            vec![]
+        }
+        child_commands::SolutionRun::Finished { reports, status } => {
+            if !status.success() && !has_timed_out(&reports) {
+                println!("Failed to run ({status}).");
+            }
+            for part in not_run_parts(&reports) {
+                println!("{}: ✖ not run", part_label(part));
+            }
+            reports
+        }
+    };
+
+    DayRun {
+        day,
+        reports,
+        failures,
+    }
+}
+
+pub fn failures_from_reports(reports: &[PartReport]) -> Vec<DayFailure> {
+    reports
+        .iter()
+        .filter(|r| r.status != PartStatus::Solved)
+        .map(|r| DayFailure::Part(r.part, r.status))
+        .collect()
+}
+
+/// A part timed out, which ends the solution binary with
+/// [`TIMED_OUT_EXIT_CODE`](crate::template::runner::TIMED_OUT_EXIT_CODE).
+pub fn has_timed_out(reports: &[PartReport]) -> bool {
+    reports.iter().any(|r| r.status == PartStatus::TimedOut)
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// Parts that did not run because an earlier part timed out. A binary reports the parts that ran, or timed out, before it
+/// ends, so any part without a report after a timeout did not run.
+pub fn not_run_parts(reports: &[PartReport]) -> Vec<u8> {
+    if !has_timed_out(reports) {
+        return vec![];
+    }
+    [1, 2]
+        .into_iter()
+        .filter(|part| !reports.iter().any(|r| r.part == *part))
+        .collect()
+}
+
+/// Longest a solution binary may run with a `timeout` before it is killed. Parsing and each part may run for the
+/// timeout, or, when benched, for the time budget and twice the timeout: no samples are taken after the budget and the
+/// timeout elapsed, and the last sample gets the full timeout to finish. The binary reports a part that runs longer
+/// itself, so this only stops binaries that hang, e.g. when the watchdog can't print its report.
+pub fn run_limit(timeout: Duration, bench: Option<&BenchOptions>) -> Duration {
+    let phase = match bench {
+        Some(bench) => timeout * 2 + bench.budget,
+        None => timeout,
+    };
+    phase * 3 + RUN_LIMIT_GRACE
+}
+
+/// Time a binary gets on top of the timeouts of its parts, e.g. to read its input.
+const RUN_LIMIT_GRACE: Duration = Duration::from_secs(2);
+
+/// Only benched parts and parts that timed out have timings, even if a bench ended after a single sample.
+pub fn timing_from_reports(reports: &[PartReport], day: Day) -> Timing {
+    let mut timing = Timing {
+        day,
+        parse: None,
+        part_1: None,
+        part_2: None,
+        combined: false,
+        total_nanos: 0_f64,
+    };
+
+    for report in reports
+        .iter()
+        .filter(|r| r.benched || r.status == PartStatus::TimedOut)
+    {
+        let timed_out = report.status == PartStatus::TimedOut;
+        let part = Some(if timed_out {
+            PartTiming::timed_out(report.stats.mean)
+        } else {
+            PartTiming::from_stats(report.stats.clone()).with_heap(report.heap)
+        });
+        match report.part {
+            PARSE_PART => timing.parse = part,
+            // NOTE: combined parts share a single timing, which is stored and counted once.
+            1 if report.combined => {
+                timing.part_1 = part;
+                timing.combined = true;
+            }
+            1 => timing.part_1 = part,
+            2 if report.combined => continue,
+            2 => timing.part_2 = part,
+            _ => continue,
+        }
+        // NOTE: the duration of a part that timed out is not known, so it is not counted.
+        if timed_out {
+            continue;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        {
+            timing.total_nanos += report.stats.mean.as_nanos() as f64;
+        }
+    }
+
+    timing
+}
+
+/// Solutions that are compiled into the main binary via `days!` run in the current process.
+pub mod in_process {
+    use super::{DayFailure, DayRun, failures_from_reports};
+    use crate::template::InputSource;
+    use crate::template::runner::{BenchOptions, DaySolution, RunOptions};
+    use std::panic::{self, AssertUnwindSafe};
+
+    /// Run a registered solution, catching panics that happen outside of its parts, e.g. a missing input file.
+    pub fn run_solution(
+        solution: &DaySolution,
+        bench: Option<&BenchOptions>,
+        input: &InputSource,
+    ) -> DayRun {
+        let options = RunOptions {
+            timed: bench.is_some(),
+            bench: bench.cloned().unwrap_or_default(),
+            input: input.clone(),
+            ..RunOptions::default()
+        };
+
+        match panic::catch_unwind(AssertUnwindSafe(|| (solution.run)(&options))) {
+            Ok(reports) => DayRun {
+                day: solution.day,
+                failures: failures_from_reports(&reports),
+                reports,
+            },
+            Err(_) => {
+                println!("Failed to run (panicked).");
+                DayRun {
+                    day: solution.day,
+                    reports: vec![],
+                    failures: vec![DayFailure::Crashed("panicked".into())],
+                }
+            }
+        }
+    }
+}
+
+/// Solutions can also run in isolated binaries.
+/// This module encapsulates interaction with these binaries, both invoking them as well as reading their JSON reports.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::{
+        DayFailure, Error, failures_from_reports, get_bin_name, get_path_for_bin, has_timed_out,
+        not_run_parts, run_limit,
+    };
+    use crate::template::runner::{BenchOptions, PartReport, print_report};
+    use crate::template::stats::Stats;
+    use crate::template::{Day, InputSource, PartStatus, SolutionError, Year};
     use std::{
-        io::{BufRead, BufReader},
+        io::{self, BufRead, BufReader},
         path::Path,
-        process::{Command, Stdio},
+        process::{Child, Command, ExitStatus, Stdio},
+        str::FromStr,
         thread,
+        time::{Duration, Instant},
     };
+    use tinyjson::JsonValue;
+
+    /// Result of invoking the solution bin for a given day.
+    pub enum SolutionRun {
+        NotScaffolded,
+        Finished {
+            reports: Vec<PartReport>,
+            status: ExitStatus,
+        },
+    }
 
     /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    pub fn run_solution(
+        year: Year,
+        day: Day,
+        bench: Option<&BenchOptions>,
+        timeout: Option<Duration>,
+        is_release: bool,
+        input: &InputSource,
+    ) -> Result<SolutionRun, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if !Path::new(&get_path_for_bin(year, day)).exists() {
+            return Ok(SolutionRun::NotScaffolded);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let mut cargo_args: Vec<String> =
+            vec!["--quiet".into(), "--bin".into(), get_bin_name(year, day)];
 
         if is_release {
-            args.push("--release");
+            cargo_args.push("--release".into());
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        // NOTE: with a timeout, the binary is built first, so the time it takes to build doesn't count towards
+        // the limit of the run.
+        if timeout.is_some() {
+            let status = Command::new("cargo")
+                .arg("build")
+                .args(&cargo_args)
+                .status()?;
+            if !status.success() {
+                return Ok(SolutionRun::Finished {
+                    reports: vec![],
+                    status,
+                });
+            }
+        }
+
+        let mut args: Vec<String> = vec!["run".into()];
+        args.extend(cargo_args);
+        args.extend(["--".into(), "--format".into(), "json".into()]);
+        args.extend(input.to_args());
+
+        if let Some(bench) = bench {
+            // mirror `--time` flag and bench options to child invocations.
+            args.push("--time".into());
+            args.extend(bench.to_args());
+        }
+
+        if let Some(timeout) = timeout {
+            args.extend(["--timeout".into(), timeout.as_secs_f64().to_string()]);
         }
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // print reports as they come in and forward any other output to stdout/stderr.
 
         let mut cmd = Command::new("cargo")
             .args(&args)
@@ -108,150 +404,394 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
-
         let thread = thread::spawn(move || {
             stderr.lines().for_each(|line| {
                 eprintln!("{}", line.unwrap());
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
-
-        thread.join().unwrap();
-        cmd.wait()?;
-
-        Ok(output)
-    }
-
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
+        let reader = {
+            let input = input.clone();
+            thread::spawn(move || {
+                let mut reports = vec![];
+                for line in stdout.lines() {
+                    let line = line.unwrap();
+                    match parse_report(&line) {
+                        Some(report) => {
+                            print_report(year, day, &report, &input);
+                            reports.push(report);
+                        }
+                        None => println!("{line}"),
+                    }
+                }
+                reports
+            })
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        let (status, killed_after) = match timeout {
+            Some(timeout) => wait_or_kill(&mut cmd, run_limit(timeout, bench))?,
+            None => (cmd.wait()?, None),
+        };
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+        let mut reports = reader.join().unwrap();
+        thread.join().unwrap();
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+        // NOTE: the part that hung never reported itself, the ones after it are reported as not run.
+        if let Some(limit) = killed_after
+            && let Some(part) = [1, 2]
+                .into_iter()
+                .find(|part| !reports.iter().any(|r| r.part == *part))
+        {
+            let report = killed_report(part, limit);
+            print_report(year, day, &report, input);
+            reports.push(report);
+        }
 
-                timings.total_nanos += nanos;
-            });
+        Ok(SolutionRun::Finished { reports, status })
+    }
 
-        timings
+    /// Waits for a solution binary to exit, and kills it once it ran for `limit`. Returns its exit status and, if it
+    /// was killed, the limit.
+    fn wait_or_kill(
+        child: &mut Child,
+        limit: Duration,
+    ) -> io::Result<(ExitStatus, Option<Duration>)> {
+        let deadline = Instant::now() + limit;
+        loop {
+            if let Some(status) = child.try_wait()? {
+                return Ok((status, None));
+            }
+            if Instant::now() >= deadline {
+                // NOTE: the binary may exit right before it is killed, which is not an error.
+                let _ = child.kill();
+                return Ok((child.wait()?, Some(limit)));
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Report of a part that was running when its binary was killed.
+    fn killed_report(part: u8, limit: Duration) -> PartReport {
+        PartReport {
+            part,
+            status: PartStatus::TimedOut,
+            answer: None,
+            message: Some(format!(
+                "{}, the binary was killed after {limit:.1?}",
+                SolutionError::TimedOut
+            )),
+            stats: Stats::single(limit),
+            combined: false,
+            benched: false,
+            heap: None,
+        }
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    /// Parse a line of output as a report. Returns [`None`] for any other output of the solution.
+    pub fn parse_report(line: &str) -> Option<PartReport> {
+        if !line.starts_with('{') {
+            return None;
+        }
+
+        let json = JsonValue::from_str(line).ok()?;
+        match PartReport::try_from(&json) {
+            Ok(report) => Some(report),
+            Err(e) => {
+                eprintln!("Could not parse report from line: {line} ({e})");
+                None
+            }
+        }
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    /// Collect the reasons why a solution run did not produce all answers.
+    pub fn parse_failures(run: &SolutionRun) -> Vec<DayFailure> {
+        match run {
+            SolutionRun::NotScaffolded => vec![DayFailure::NotScaffolded],
+            SolutionRun::Finished { reports, status } => {
+                let mut failures = failures_from_reports(reports);
+
+                // NOTE: a timed out part ends the binary with an error, but it is already reported as a failure.
+                if !status.success() && !has_timed_out(reports) {
+                    failures.push(DayFailure::Crashed(status.to_string()));
+                }
+                failures.extend(not_run_parts(reports).into_iter().map(DayFailure::NotRun));
+
+                failures
+            }
+        }
     }
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{SolutionRun, parse_failures, parse_report};
+        use std::process::ExitStatus;
+        use std::time::Duration;
 
-        use crate::day;
+        use crate::template::{PartStatus, run_multi::DayFailure, runner::TIMED_OUT_EXIT_CODE};
+
+        #[cfg(unix)]
+        fn exit_status(code: i32) -> ExitStatus {
+            use std::os::unix::process::ExitStatusExt;
+            ExitStatus::from_raw(code << 8)
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+        fn parses_reports() {
+            let report = parse_report(r#"{"part":1,"status":"solved","answer":"0","message":null,"duration_nanos":74.0,"median_nanos":74.0,"p95_nanos":74.0,"outliers":0,"min_nanos":70.0,"max_nanos":80.0,"stddev_nanos":2.0,"samples":100000}"#).unwrap();
+            assert_eq!(report.part, 1);
+            assert_eq!(report.answer, Some("0".into()));
+            assert_eq!(report.stats.mean, Duration::from_nanos(74));
+            assert_eq!(report.stats.samples, 100000);
         }
 
         #[test]
         fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+            let report = parse_report(r#"{"part":1,"status":"solved","answer":"@ @ @ ( ) ms (2s @ 5 samples)","message":null,"duration_nanos":2000000000,"median_nanos":2000000000,"p95_nanos":2000000000,"outliers":0,"min_nanos":2000000000,"max_nanos":2000000000,"stddev_nanos":0,"samples":5}"#).unwrap();
+            assert_eq!(report.answer, Some("@ @ @ ( ) ms (2s @ 5 samples)".into()));
+            assert_eq!(report.stats.mean, Duration::from_secs(2));
+        }
+
+        #[test]
+        fn ignores_other_output() {
+            assert_eq!(parse_report("Part 1: 0 (74.13ns @ 100000 samples)"), None);
+            assert_eq!(parse_report("{ debug output }"), None);
+            assert_eq!(parse_report(""), None);
+        }
+
+        #[test]
+        #[cfg(unix)]
+        fn parses_failure_categories() {
+            let run = SolutionRun::Finished {
+                reports: [
+                    r#"{"part":1,"status":"parse error","answer":null,"message":"parse error at line 1, column 3: expected digit","duration_nanos":1,"median_nanos":1,"p95_nanos":1,"outliers":0,"min_nanos":1,"max_nanos":1,"stddev_nanos":0,"samples":1}"#,
+                    r#"{"part":2,"status":"unsolvable","answer":null,"message":"unsolvable: no path","duration_nanos":1,"median_nanos":1,"p95_nanos":1,"outliers":0,"min_nanos":1,"max_nanos":1,"stddev_nanos":0,"samples":1}"#,
+                ]
+                .iter()
+                .filter_map(|l| parse_report(l))
+                .collect(),
+                status: exit_status(0),
+            };
+            assert_eq!(
+                parse_failures(&run),
+                vec![
+                    DayFailure::Part(1, PartStatus::ParseError),
+                    DayFailure::Part(2, PartStatus::Unsolvable)
+                ]
+            );
+        }
+
+        #[test]
+        #[cfg(unix)]
+        fn parses_timed_out_runs() {
+            let run = SolutionRun::Finished {
+                reports: [
+                    r#"{"part":1,"status":"timed out","answer":null,"message":"timed out","duration_nanos":5000000000,"median_nanos":5000000000,"p95_nanos":5000000000,"outliers":0,"min_nanos":5000000000,"max_nanos":5000000000,"stddev_nanos":0,"samples":1}"#,
+                ]
+                .iter()
+                .filter_map(|l| parse_report(l))
+                .collect(),
+                status: exit_status(TIMED_OUT_EXIT_CODE),
+            };
+            assert_eq!(
+                parse_failures(&run),
+                vec![
+                    DayFailure::Part(1, PartStatus::TimedOut),
+                    DayFailure::NotRun(2)
+                ]
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
+        #[cfg(unix)]
+        fn parses_crashed_runs() {
+            let run = SolutionRun::Finished {
+                reports: vec![],
+                status: exit_status(101),
+            };
+            let failures = parse_failures(&run);
+            assert_eq!(failures.len(), 1);
+            assert!(matches!(failures[0], DayFailure::Crashed(_)));
+        }
+
+        #[test]
+        fn parses_unscaffolded_days() {
+            assert_eq!(
+                parse_failures(&SolutionRun::NotScaffolded),
+                vec![DayFailure::NotScaffolded]
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
         }
     }
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{failures_from_reports, not_run_parts, run_limit, timing_from_reports};
+    use crate::{
+        day,
+        template::{
+            PartStatus,
+            heap::HeapStats,
+            run_multi::DayFailure,
+            runner::{BenchOptions, PARSE_PART, PartReport},
+            stats::Stats,
+        },
+    };
+    use std::time::Duration;
+
+    fn get_mock_reports() -> Vec<PartReport> {
+        vec![
+            PartReport {
+                part: 1,
+                status: PartStatus::Solved,
+                answer: Some("42".into()),
+                message: None,
+                stats: Stats {
+                    samples: 100,
+                    ..Stats::single(Duration::from_nanos(74_130))
+                },
+                combined: false,
+                benched: true,
+                heap: None,
+            },
+            PartReport {
+                part: 2,
+                status: PartStatus::Unsolvable,
+                answer: None,
+                message: Some("unsolvable: no path".into()),
+                stats: Stats::single(Duration::from_nanos(10)),
+                combined: false,
+                benched: false,
+                heap: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn collects_timings_of_benched_parts() {
+        let timing = timing_from_reports(&get_mock_reports(), day!(1));
+        let part_1 = timing.part_1.unwrap();
+        assert_eq!(part_1.mean, Duration::from_nanos(74_130));
+        assert_eq!(part_1.samples(), Some(100));
+        assert_eq!(timing.part_2, None);
+        assert_eq!(timing.total_nanos, 74_130_f64);
+    }
+
+    #[test]
+    fn collects_timings_of_single_sample_benches() {
+        let mut reports = get_mock_reports();
+        reports[1] = PartReport {
+            status: PartStatus::Solved,
+            answer: Some("7".into()),
+            message: None,
+            stats: Stats::single(Duration::from_secs(23)),
+            benched: true,
+            ..reports[1].clone()
+        };
+
+        let timing = timing_from_reports(&reports, day!(1));
+        let part_2 = timing.part_2.unwrap();
+        assert_eq!(part_2.mean, Duration::from_secs(23));
+        assert_eq!(part_2.samples(), Some(1));
+    }
+
+    #[test]
+    fn collects_timings_of_the_parse_phase() {
+        let mut reports = get_mock_reports();
+        reports.push(PartReport {
+            part: PARSE_PART,
+            status: PartStatus::Solved,
+            answer: None,
+            message: None,
+            stats: Stats {
+                samples: 100,
+                ..Stats::single(Duration::from_nanos(870))
+            },
+            combined: false,
+            benched: true,
+            heap: Some(HeapStats {
+                total_bytes: 2048,
+                peak_bytes: 1024,
+                allocations: 3,
+            }),
+        });
+
+        let timing = timing_from_reports(&reports, day!(1));
+        let parse = timing.parse.unwrap();
+        assert_eq!(parse.mean, Duration::from_nanos(870));
+        assert_eq!(parse.heap.map(|heap| heap.peak_bytes), Some(1024));
+        assert_eq!(timing.total_nanos, 75_000_f64);
+    }
+
+    #[test]
+    fn collects_timings_of_combined_parts() {
+        let reports: Vec<PartReport> = get_mock_reports()
+            .into_iter()
+            .map(|report| PartReport {
+                combined: true,
+                ..report
+            })
+            .collect();
+
+        let timing = timing_from_reports(&reports, day!(1));
+        assert!(timing.combined);
+        assert_eq!(timing.part_1.unwrap().mean, Duration::from_nanos(74_130));
+        assert_eq!(timing.part_2, None);
+        assert_eq!(timing.total_nanos, 74_130_f64);
+    }
+
+    #[test]
+    fn collects_timed_out_parts() {
+        let mut reports = get_mock_reports();
+        reports[1] = PartReport {
+            status: PartStatus::TimedOut,
+            message: Some("timed out".into()),
+            stats: Stats::single(Duration::from_secs(5)),
+            ..reports[1].clone()
+        };
+
+        let timing = timing_from_reports(&reports, day!(1));
+        let part_2 = timing.part_2.unwrap();
+        assert!(part_2.timed_out);
+        assert_eq!(part_2.mean, Duration::from_secs(5));
+        assert_eq!(timing.total_nanos, 74_130_f64);
+    }
+
+    #[test]
+    fn finds_parts_that_did_not_run() {
+        let mut reports = get_mock_reports();
+        assert!(not_run_parts(&reports).is_empty());
+
+        reports[0].status = PartStatus::TimedOut;
+        reports.truncate(1);
+        assert_eq!(not_run_parts(&reports), vec![2]);
+
+        reports[0].part = PARSE_PART;
+        assert_eq!(not_run_parts(&reports), vec![1, 2]);
+    }
+
+    #[test]
+    fn limits_runs_with_a_timeout() {
+        let bench = BenchOptions {
+            budget: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 100,
+        };
+
+        assert_eq!(
+            run_limit(Duration::from_secs(10), None),
+            Duration::from_secs(32)
+        );
+        assert_eq!(
+            run_limit(Duration::from_secs(10), Some(&bench)),
+            Duration::from_secs(65)
+        );
+    }
+
+    #[test]
+    fn collects_failed_parts() {
+        assert_eq!(
+            failures_from_reports(&get_mock_reports()),
+            vec![DayFailure::Part(2, PartStatus::Unsolvable)]
+        );
+    }
+}
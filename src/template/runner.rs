@@ -1,100 +1,748 @@
 /// Encapsulates code that interacts with solution functions.
+use std::any::Any;
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, process, thread};
+use std::{collections::HashMap, str::FromStr};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use tinyjson::JsonValue;
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+use crate::template::answers::{self, Answers};
+use crate::template::commands::scaffold;
+use crate::template::aoc_client::{AocClient, SubmitResponse, get_puzzle_path, store_puzzle};
+use crate::template::heap::{self, HeapStats};
+use crate::template::stats::Stats;
+use crate::template::submissions::{self, Hint, Submissions, SubmitOutcome};
+use crate::template::{ANSI_BOLD, InputSource, config};
+use crate::template::{
+    ANSI_ITALIC, ANSI_RESET, Day, IntoSolution, PartStatus, SolutionError, Year,
+};
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+/// Output format of solution binaries.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable output with progress indicators.
+    #[default]
+    Human,
+    /// One JSON object per part and line, see [`PartReport`].
+    Json,
+}
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+/// Options that control how long a part is benched.
+#[derive(Clone, Debug)]
+pub struct BenchOptions {
+    /// Approximate time to spend on measurements, not counting warmup.
+    pub budget: Duration,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            budget: config().bench_budget,
+            min_samples: 10,
+            max_samples: 10000,
+        }
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Copy, T>(
-    func: impl Fn(I) -> T,
+impl BenchOptions {
+    /// Arguments that pass these options on to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        vec![
+            "--budget".into(),
+            self.budget.as_secs_f64().to_string(),
+            "--min-samples".into(),
+            self.min_samples.to_string(),
+        ]
+    }
+}
+
+/// Options that control how solution parts are run.
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    /// Bench parts instead of running them once.
+    pub timed: bool,
+    pub bench: BenchOptions,
+    /// Submit the answer for this part.
+    pub submit: Option<u8>,
+    /// Submit even if earlier responses tell that the answer is wrong.
+    pub force: bool,
+    pub format: OutputFormat,
+    pub input: InputSource,
+    /// Puzzle parameters from `--param <name>=<value>`, see [`PuzzleParams`](crate::template::PuzzleParams).
+    pub params: Vec<(String, String)>,
+    /// Time a part may run before it is reported as timed out and the process exits, see [`TIMED_OUT_EXIT_CODE`].
+    pub timeout: Option<Duration>,
+}
+
+impl RunOptions {
+    /// Reads the options from the arguments passed to a solution binary.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let submit = args.iter().position(|x| x == "--submit").map(|index| {
+            let Some(Ok(part)) = args.get(index + 1).map(|x| x.parse::<u8>()) else {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+                process::exit(1);
+            };
+            part
+        });
+
+        let format = args.iter().position(|x| x == "--format").map(|index| {
+            match args.get(index + 1).map(String::as_str) {
+                Some("human") => OutputFormat::Human,
+                Some("json") => OutputFormat::Json,
+                _ => {
+                    eprintln!("Unexpected command-line input. Format: --format <human|json>");
+                    process::exit(1);
+                }
+            }
+        });
+
+        let mut bench = BenchOptions::default();
+
+        if let Some(index) = args.iter().position(|x| x == "--budget") {
+            let Some(Some(budget)) = args.get(index + 1).map(|x| parse_budget(x)) else {
+                eprintln!("Unexpected command-line input. Format: --budget <seconds>");
+                process::exit(1);
+            };
+            bench.budget = budget;
+        }
+
+        if let Some(index) = args.iter().position(|x| x == "--min-samples") {
+            let Some(Ok(min_samples)) = args.get(index + 1).map(|x| x.parse::<u128>()) else {
+                eprintln!("Unexpected command-line input. Format: --min-samples <count>");
+                process::exit(1);
+            };
+            bench.min_samples = min_samples.clamp(1, bench.max_samples);
+        }
+
+        let input_arg = |name: &str| {
+            args.iter().position(|x| x == name).map(|index| {
+                let Some(value) = args.get(index + 1) else {
+                    eprintln!("Unexpected command-line input. Format: {name} <value>");
+                    process::exit(1);
+                };
+                value.as_str()
+            })
+        };
+
+        let input = match (input_arg("--input"), input_arg("--input-file")) {
+            (Some(_), Some(_)) => {
+                eprintln!("Unexpected command-line input. Pass either --input or --input-file.");
+                process::exit(1);
+            }
+            (Some(name), None) => InputSource::named(name),
+            (None, Some(path)) => InputSource::file(path),
+            (None, None) => InputSource::Default,
+        };
+
+        let params = args
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| *x == "--param")
+            .map(|(index, _)| {
+                let Some(Ok(param)) = args.get(index + 1).map(|x| parse_param(x)) else {
+                    eprintln!("Unexpected command-line input. Format: --param <name>=<value>");
+                    process::exit(1);
+                };
+                param
+            })
+            .collect();
+
+        let timeout = match args.iter().position(|x| x == "--timeout") {
+            Some(index) => {
+                let Some(Some(timeout)) = args.get(index + 1).map(|x| parse_timeout(x)) else {
+                    eprintln!("Unexpected command-line input. Format: --timeout <seconds>");
+                    process::exit(1);
+                };
+                Some(timeout)
+            }
+            None => config().timeout,
+        };
+
+        Self {
+            timed: args.iter().any(|x| x == "--time"),
+            bench,
+            submit,
+            force: args.iter().any(|x| x == "--force"),
+            format: format.unwrap_or_default(),
+            input,
+            params,
+            timeout,
+        }
+    }
+}
+
+/// Parses a time budget from a number of seconds. Negative, infinite and overflowing numbers are rejected.
+pub fn parse_budget(s: &str) -> Option<Duration> {
+    s.trim()
+        .parse::<f64>()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+}
+
+/// Parses a timeout from a positive number of seconds.
+pub fn parse_timeout(s: &str) -> Option<Duration> {
+    s.trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|secs| *secs > 0_f64)
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+}
+
+/// Parses a puzzle parameter from `<name>=<value>`.
+pub fn parse_param(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => {
+            Ok((key.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(format!("expecting `<name>=<value>`, got `{s}`")),
+    }
+}
+
+/// Number of the parse phase in reports and timings, the parts are numbered from 1.
+pub const PARSE_PART: u8 = 0;
+
+/// Name of a part in the output, e.g. `Part 1`, or `Parse` for the parse phase.
+pub fn part_label(part: u8) -> String {
+    match part {
+        PARSE_PART => "Parse".into(),
+        part => format!("Part {part}"),
+    }
+}
+
+/// Exit code of solution binaries that end because a part timed out, the same as the one of the `timeout` command.
+pub const TIMED_OUT_EXIT_CODE: i32 = 124;
+
+/// Outcome of running a single part, or the parse phase, see [`run_parse`].
+/// Solution binaries print this as JSON when run with `--format json`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartReport {
+    pub part: u8,
+    pub status: PartStatus,
+    pub answer: Option<String>,
+    /// Description of the error, if the part failed.
+    pub message: Option<String>,
+    pub stats: Stats,
+    /// The part was solved in one pass with the other part, see [`run_both`]. The stats are the ones of that pass.
+    pub combined: bool,
+    /// The part was benched, so its stats are the ones of the bench. This is decided by the run, not by the number of
+    /// samples, as a bench of a slow part can end after a single sample.
+    pub benched: bool,
+    /// Heap usage of the first execution. Missing in reports of binaries that were built before it was measured.
+    pub heap: Option<HeapStats>,
+}
+
+/// A solution that is compiled into the main binary, so it can be run without spawning a process.
+/// Registered with the [`days!`](crate::days) macro.
+#[derive(Clone, Copy)]
+pub struct DaySolution {
+    pub year: Year,
+    pub day: Day,
+    pub run: fn(&RunOptions) -> Vec<PartReport>,
+}
+
+pub fn run_part<I: Copy, R: IntoSolution>(
+    func: impl Fn(I) -> R,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    year: Year,
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+) -> PartReport {
+    let part_str = part_label(part);
+    let is_human = options.format == OutputFormat::Human;
+
+    let bench = options.timed.then_some(&options.bench);
+    let watchdog = Watchdog::start(&[part], false, options);
+
+    let (result, stats, heap) = run_timed(func, input, bench, watchdog, is_human, |result| {
+        if is_human {
+            print_result(result, &part_str, "");
+        }
+    });
+    let benched = bench.is_some() && result.is_ok();
+
+    report_part(
+        result,
+        part,
+        stats,
+        Some(heap),
+        false,
+        benched,
+        year,
+        day,
+        options,
+    )
+}
+
+/// Runs a solution that solves both parts in one pass, see the `both` option of `solution!`. The parts are timed
+/// together, so both reports are marked as `combined` and hold the stats of the combined run.
+pub fn run_both<I: Copy, A: IntoSolution, B: IntoSolution>(
+    func: impl Fn(I) -> (A, B),
+    input: I,
+    year: Year,
+    day: Day,
+    options: &RunOptions,
+) -> Vec<PartReport> {
+    let is_human = options.format == OutputFormat::Human;
+    let watchdog = Watchdog::start(&[1, 2], true, options);
+
+    let timer = Instant::now();
+    let ((result_1, result_2), heap) = {
+        #[cfg(feature = "dhat-heap")]
+        let _profiler = dhat::Profiler::new_heap();
+
+        heap::measure(|| {
+            panic::catch_unwind(AssertUnwindSafe(|| {
+                let (part_1, part_2) = func(input);
+                (part_1.into_solution(), part_2.into_solution())
+            }))
+            .unwrap_or_else(|payload| {
+                let message = panic_message(&*payload);
+                (
+                    Err(SolutionError::Panicked(message.clone())),
+                    Err(SolutionError::Panicked(message)),
+                )
+            })
+        })
+    };
+    let base_time = timer.elapsed();
+
+    // NOTE: a combined run is benched as long as one of its parts succeeds, e.g. before part two is implemented.
+    let benched = options.timed && (result_1.is_ok() || result_2.is_ok());
+    let stats = if benched {
+        if is_human {
+            print_result(&result_1, &part_label(1), "");
+        }
+        let stop_at = watchdog.as_ref().map(|w| w.extend(&options.bench));
+        bench(&func, input, &base_time, &options.bench, is_human, stop_at)
+    } else {
+        Stats::single(base_time)
+    };
+    drop(watchdog);
+
+    vec![
+        report_part(
+            result_1,
+            1,
+            stats.clone(),
+            Some(heap),
+            true,
+            benched,
+            year,
+            day,
+            options,
+        ),
+        report_part(
+            result_2,
+            2,
+            stats,
+            Some(heap),
+            true,
+            benched,
+            year,
+            day,
+            options,
+        ),
+    ]
+}
+
+/// Creates the report of a part from its result, prints it and submits the answer if requested.
+#[allow(clippy::too_many_arguments)]
+fn report_part<T: Display>(
+    result: Result<T, SolutionError>,
+    part: u8,
+    stats: Stats,
+    heap: Option<HeapStats>,
+    combined: bool,
+    benched: bool,
+    year: Year,
+    day: Day,
+    options: &RunOptions,
+) -> PartReport {
+    let report = PartReport {
+        part,
+        status: match &result {
+            Ok(_) => PartStatus::Solved,
+            Err(err) => err.status(),
+        },
+        answer: result.as_ref().ok().map(ToString::to_string),
+        message: result.as_ref().err().map(ToString::to_string),
+        stats,
+        combined,
+        benched,
+        heap,
+    };
+
+    match options.format {
+        OutputFormat::Human => {
+            let suffix = format_timing(&report) + &format_check(year, day, &report, &options.input);
+            print_result(&result, &part_label(part), &suffix);
+            print_report_stats(&report);
+        }
+        OutputFormat::Json => println!("{}", JsonValue::from(&report).stringify().unwrap()),
+    }
+
+    if let Ok(result) = result
+        && let Some(code) = submit_result(result, year, day, part, options)
+        && code != 0
+    {
+        process::exit(code);
+    }
+
+    report
+}
+
+/// Runs the `parse` function of a solution once, and benches it like a part when timing. Its output is shared by
+/// both parts, so it is not part of their timings. Returns the output, if parsing succeeded, and a report with the
+/// number [`PARSE_PART`].
+pub fn run_parse<T>(
+    func: impl Fn(&str) -> T,
+    input: &str,
+    options: &RunOptions,
+) -> (Option<T>, PartReport) {
+    let part_str = part_label(PARSE_PART);
+    let is_human = options.format == OutputFormat::Human;
+    let watchdog = Watchdog::start(&[PARSE_PART], false, options);
+
     let timer = Instant::now();
-    let result = {
+    let (output, heap) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        heap::measure(|| {
+            panic::catch_unwind(AssertUnwindSafe(|| func(input)))
+                .map_err(|payload| SolutionError::Panicked(panic_message(&*payload)))
+        })
+    };
+    let base_time = timer.elapsed();
+
+    // NOTE: parsing has no answer, a check mark tells that it succeeded.
+    let result = output.as_ref().map(|_| "✔");
+
+    let stats = match (&result, options.timed) {
+        (Ok(_), true) => {
+            if is_human {
+                print_result(&result, &part_str, "");
+            }
+            let stop_at = watchdog.as_ref().map(|w| w.extend(&options.bench));
+            bench(&func, input, &base_time, &options.bench, is_human, stop_at)
+        }
+        _ => Stats::single(base_time),
+    };
+    drop(watchdog);
+
+    let report = PartReport {
+        part: PARSE_PART,
+        status: match &result {
+            Ok(_) => PartStatus::Solved,
+            Err(err) => err.status(),
+        },
+        answer: None,
+        message: result.as_ref().err().map(ToString::to_string),
+        stats,
+        combined: false,
+        benched: result.is_ok() && options.timed,
+        heap: Some(heap),
+    };
+
+    match options.format {
+        OutputFormat::Human => {
+            print_result(&result, &part_str, &format_timing(&report));
+            print_stats(&report.stats);
+        }
+        OutputFormat::Json => println!("{}", JsonValue::from(&report).stringify().unwrap()),
+    }
+
+    (output.ok(), report)
+}
+
+/// Print a report in the same format as [`run_part`] prints its result.
+pub fn print_report(year: Year, day: Day, report: &PartReport, input: &InputSource) {
+    let result = match (&report.answer, report.status) {
+        (Some(answer), PartStatus::Solved) => Ok(answer.as_str()),
+        // NOTE: parsing has no answer, a check mark tells that it succeeded, like `run_parse` prints it.
+        (None, PartStatus::Solved) if report.part == PARSE_PART => Ok("✔"),
+        _ => Err(report
+            .message
+            .clone()
+            .unwrap_or_else(|| report.status.to_string())),
+    };
+
+    print_result(
+        &result,
+        &part_label(report.part),
+        &(format_timing(report) + &format_check(year, day, report, input)),
+    );
+    print_report_stats(report);
+}
+
+/// Run a solution part. The behavior differs depending on whether we are benching:
+///  1. by default, the function is executed once.
+///  2. with `bench_options`, the function is benched (approx. the time budget or the minimum number of samples,
+///     whatever takes longer.)
+///
+/// A panic in the first execution is caught and reported as [`SolutionError::Panicked`]. Failed parts are not benched.
+/// The heap usage is measured during the first execution. The `watchdog` guards it and the bench, if given.
+fn run_timed<I: Copy, R: IntoSolution>(
+    func: impl Fn(I) -> R,
+    input: I,
+    bench_options: Option<&BenchOptions>,
+    watchdog: Option<Watchdog>,
+    show_progress: bool,
+    hook: impl Fn(&Result<R::Answer, SolutionError>),
+) -> (Result<R::Answer, SolutionError>, Stats, HeapStats) {
+    let timer = Instant::now();
+    let (result, heap) = {
+        #[cfg(feature = "dhat-heap")]
+        let _profiler = dhat::Profiler::new_heap();
+
+        heap::measure(|| {
+            panic::catch_unwind(AssertUnwindSafe(|| func(input).into_solution()))
+                .unwrap_or_else(|payload| Err(SolutionError::Panicked(panic_message(&*payload))))
+        })
     };
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if let (Ok(_), Some(bench_options)) = (&result, bench_options) {
+        let stop_at = watchdog.as_ref().map(|w| w.extend(bench_options));
+        bench(
+            func,
+            input,
+            &base_time,
+            bench_options,
+            show_progress,
+            stop_at,
+        )
     } else {
-        (base_time, 1)
+        Stats::single(base_time)
     };
+    drop(watchdog);
+
+    (result, stats, heap)
+}
 
-    (result, run.0, run.1)
+/// Guards the execution of parts, which runs on the current thread and can't be stopped otherwise. Unless it is
+/// dropped before its deadline, it reports the parts as timed out and ends the process.
+///
+/// The deadline is the timeout of the run options, and is extended by [`Watchdog::extend`] when the parts are benched.
+struct Watchdog {
+    started: Instant,
+    timeout: Duration,
+    deadline: mpsc::Sender<Instant>,
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+impl Watchdog {
+    fn start(parts: &[u8], combined: bool, options: &RunOptions) -> Option<Self> {
+        let timeout = options.timeout?;
+        let started = Instant::now();
+        let format = options.format;
+        let message = format!("{} after {timeout:.1?}", SolutionError::TimedOut);
+        let reports: Vec<PartReport> = parts
+            .iter()
+            .map(|&part| PartReport {
+                part,
+                status: PartStatus::TimedOut,
+                answer: None,
+                message: Some(message.clone()),
+                stats: Stats::single(timeout),
+                combined,
+                benched: false,
+                heap: None,
+            })
+            .collect();
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+        let (sender, receiver) = mpsc::channel::<Instant>();
+
+        // NOTE: dropping the watchdog disconnects the channel, which ends the wait before the deadline.
+        thread::spawn(move || {
+            let mut deadline = started + timeout;
+            loop {
+                match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                    Ok(extended) => deadline = extended,
+                    Err(RecvTimeoutError::Disconnected) => return,
+                    Err(RecvTimeoutError::Timeout) => break,
+                }
+            }
+
+            for report in &reports {
+                match format {
+                    OutputFormat::Human => print_result(
+                        &Err::<&str, _>(&message),
+                        &part_label(report.part),
+                        &format_timing(report),
+                    ),
+                    OutputFormat::Json => {
+                        println!("{}", JsonValue::from(report).stringify().unwrap());
+                    }
+                }
+            }
+            process::exit(TIMED_OUT_EXIT_CODE);
+        });
+
+        Some(Self {
+            started,
+            timeout,
+            deadline: sender,
+        })
+    }
+
+    /// Keeps guarding the parts while they are benched. Returns when to stop taking samples: once the time budget
+    /// and the timeout have elapsed since the start. The sample that runs at that point gets the full timeout to
+    /// finish before the parts are reported as timed out.
+    fn extend(&self, options: &BenchOptions) -> Instant {
+        let stop_at = self.started + self.timeout + options.budget;
+        let _ = self.deadline.send(stop_at + self.timeout);
+        stop_at
+    }
+}
+
+/// Benches a part. With `stop_at`, no samples are taken after that point, even if fewer than the minimum were taken.
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    options: &BenchOptions,
+    show_progress: bool,
+    stop_at: Option<Instant>,
+) -> Stats {
+    if show_progress {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
+
+    let base_nanos = cmp::max(base_time.as_nanos(), 10);
+
+    // NOTE: the first execution already warmed up caches, which is enough for slow parts.
+    let warmup_iterations = (options.budget.as_nanos() / 10 / base_nanos).min(1000);
+
+    let is_stopped = || stop_at.is_some_and(|stop_at| Instant::now() >= stop_at);
+
+    for _ in 0..warmup_iterations {
+        if is_stopped() {
+            break;
+        }
+        black_box(func(black_box(input)));
+    }
 
     let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+        (options.budget.as_nanos() / base_nanos).clamp(options.min_samples, options.max_samples);
 
     let mut timers: Vec<Duration> = vec![];
 
     for _ in 0..bench_iterations {
+        // NOTE: at least one sample is taken, the stats need it.
+        if !timers.is_empty() && is_stopped() {
+            break;
+        }
         let timer = Instant::now();
         black_box(func(black_box(input)));
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    Stats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn format_duration(stats: &Stats) -> String {
+    let Stats { mean, samples, .. } = stats;
+    if *samples == 1 {
+        format!("{mean:.1?}")
+    } else {
+        format!("{mean:.1?} @ {samples} samples")
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+/// Duration and heap usage of a part. Combined parts show the ones of both parts once, next to the first part.
+fn format_timing(report: &PartReport) -> String {
+    if report.combined && report.part != 1 {
+        return " (timed with part 1)".into();
+    }
+
+    let mut details = vec![format_duration(&report.stats)];
+    if let Some(heap) = &report.heap {
+        details.push(heap.to_string());
+    }
+    if report.combined {
+        details.push("both parts".into());
+    }
+
+    format!(" ({})", details.join(", "))
+}
+
+/// Mark the answer of a solved part as correct, wrong or unknown, according to the recorded answers.
+/// Recorded answers belong to the downloaded input, so answers of other inputs are not marked.
+fn format_check(year: Year, day: Day, report: &PartReport, input: &InputSource) -> String {
+    match &report.answer {
+        Some(answer) if report.status == PartStatus::Solved && input.is_default() => {
+            match Answers::read_from_file(year) {
+                Ok(answers) => format!(" {}", answers.check(day, report.part, answer)),
+                Err(e) => format!(" ❔ could not read the answers, {e}"),
+            }
+        }
+        _ => String::new(),
+    }
+}
+
+/// Print the stats of a report below its result, once for combined parts.
+fn print_report_stats(report: &PartReport) {
+    if !report.combined || report.part == 1 {
+        print_stats(&report.stats);
+    }
+}
+
+/// Print the spread of benched measurements below the result.
+fn print_stats(stats: &Stats) {
+    if stats.samples == 1 {
+        return;
+    }
+
+    let Stats {
+        median,
+        p95,
+        min,
+        max,
+        stddev,
+        outliers,
+        ..
+    } = stats;
+
+    let outliers_str = if *outliers > 0 {
+        format!(" · {outliers} outliers rejected")
+    } else {
+        String::new()
+    };
+
+    println!(
+        "        {ANSI_ITALIC}median {median:.1?} · p95 {p95:.1?} · min {min:.1?} · max {max:.1?} · σ {stddev:.1?}{outliers_str}{ANSI_RESET}"
+    );
+}
+
+/// Extracts the message from a panic payload, which is either a `&str` or a `String`.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        "unknown panic payload".into()
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result<T: Display, E: Display>(result: &Result<T, E>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
+        Ok(result) => {
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
@@ -114,54 +762,287 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Err(err) => {
+            // NOTE: failure messages start with their status, see `PartStatus::from_str`.
+            let err = err.to_string();
+            let symbol = match PartStatus::from_str(&err) {
+                Ok(PartStatus::TimedOut) => "⏱",
+                _ => "✖",
+            };
+
             if is_intermediate_result {
-                print!("{part}: ✖");
+                print!("{part}: {symbol}");
             } else {
                 print!("\r");
-                println!("{part}: ✖             ");
+                println!("{part}: {symbol} {err}");
             }
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+/// Try to submit one part of the solution if:
+///  1. `--submit` was passed for this part.
+///  2. a session cookie is configured.
+///  3. earlier responses don't tell that the answer is wrong, unless `--force` was passed.
+///  4. the part ran against the downloaded input.
+///
+/// Outcomes are recorded in the submissions file, accepted answers also in the answers file.
+/// Returns the exit code for the outcome, see [`SubmitOutcome::exit_code`].
 fn submit_result<T: Display>(
     result: T,
+    year: Year,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
-
-    if !args.contains(&"--submit".into()) {
+    options: &RunOptions,
+) -> Option<i32> {
+    if options.submit != Some(part) {
         return None;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+    if !options.input.is_default() {
+        eprintln!(
+            "Not submitting {result}: it is the answer of the {} input. Only answers of the downloaded input can be submitted.",
+            options.input
+        );
         process::exit(1);
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    let client = AocClient::from_env(year).unwrap_or_else(|e| {
+        eprintln!("failed to create client: {e}");
+        process::exit(1);
+    });
+
+    let answer = result.to_string();
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+    // NOTE: even with --force, an unreadable file would be lost when recording the outcome.
+    let submissions = Submissions::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Not submitting {answer}: could not read the earlier submissions, {e}");
         process::exit(1);
+    });
+
+    if !options.force
+        && let Err(refusal) = submissions.guard(day, part, &answer)
+    {
+        eprintln!("Not submitting {answer}: {refusal}. Pass --force to submit anyway.");
+        process::exit(1);
+    }
+
+    println!("Submitting result...");
+
+    let outcome = match client.submit(day, part, &answer) {
+        Ok(SubmitResponse {
+            outcome: Some(outcome),
+            ..
+        }) => outcome,
+        Ok(SubmitResponse {
+            outcome: None,
+            message,
+        }) => {
+            eprintln!("Could not recognize the response:\n{message}");
+            return Some(1);
+        }
+        Err(e) => {
+            eprintln!("failed to submit: {e}");
+            return Some(1);
+        }
     };
 
-    if part_submit != part {
-        return None;
+    println!("{}", format_outcome(outcome, &answer));
+
+    if let Err(e) = submissions::record(year, day, part, &answer, outcome) {
+        eprintln!("Failed to record the submission: {e}");
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+    if outcome == SubmitOutcome::Correct {
+        match answers::record(year, day, part, &answer) {
+            Ok(()) => println!("Recorded the answer of part {part} as correct."),
+            Err(e) => eprintln!("Failed to record the answer: {e}"),
+        }
+
+        // NOTE: solving part one unlocks part two, so the stored description becomes outdated.
+        if part == 1 {
+            match store_puzzle(&client, day) {
+                Ok(puzzle) => {
+                    println!("Added part two to \"{}\".", get_puzzle_path(year, day));
+                    scaffold::add_unlocked_examples(year, day, &puzzle);
+                }
+                Err(e) => eprintln!("Failed to download part two: {e}"),
+            }
+        }
+    }
+
+    Some(outcome.exit_code())
+}
+
+fn format_outcome(outcome: SubmitOutcome, answer: &str) -> String {
+    match outcome {
+        SubmitOutcome::Correct => format!("⭐ {answer} is the right answer!"),
+        SubmitOutcome::Incorrect { hint: None } => format!("❌ {answer} is not the right answer."),
+        SubmitOutcome::Incorrect {
+            hint: Some(Hint::TooHigh),
+        } => format!("❌ {answer} is not the right answer, it is too high."),
+        SubmitOutcome::Incorrect {
+            hint: Some(Hint::TooLow),
+        } => format!("❌ {answer} is not the right answer, it is too low."),
+        SubmitOutcome::AlreadySolved => "✅ This part was already solved.".into(),
+        SubmitOutcome::RateLimited { wait: Some(wait) } => {
+            let secs = wait.as_secs();
+            format!(
+                "⏳ An answer was submitted too recently, wait {}m {}s before submitting again.",
+                secs / 60,
+                secs % 60
+            )
+        }
+        SubmitOutcome::RateLimited { wait: None } => {
+            "⏳ An answer was submitted too recently, wait before submitting again.".into()
+        }
+        SubmitOutcome::WrongLevel => {
+            "⚠️ This part can't be submitted yet, solve the previous part first.".into()
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn optional_string_from_json(
+    json: &HashMap<String, JsonValue>,
+    key: &str,
+) -> Result<Option<String>, String> {
+    json.get(key)
+        .map(|v| if v.is_null() { None } else { v.get::<String>() })
+        .ok_or_else(|| format!("Expected report.{key} to be null or string."))
+        .map(|v| v.cloned())
+}
+
+impl From<&PartReport> for JsonValue {
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.label().into()),
         );
-        process::exit(1);
+
+        for (key, str) in [("answer", &value.answer), ("message", &value.message)] {
+            map.insert(
+                key.into(),
+                match str {
+                    Some(x) => JsonValue::String(x.clone()),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        map.insert("combined".into(), JsonValue::Boolean(value.combined));
+        map.insert("benched".into(), JsonValue::Boolean(value.benched));
+        value.stats.insert_into(&mut map);
+        if let Some(heap) = &value.heap {
+            heap.insert_into(&mut map);
+        }
+
+        JsonValue::Object(map)
     }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .and_then(|v| u8::try_from(*v as u64).ok())
+            .ok_or("Expected report.part to be a part number.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .and_then(|v| PartStatus::from_str(v).ok())
+            .ok_or("Expected report.status to be a part status.")?;
+
+        let stats = Stats::from_json(json)?;
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+        Ok(PartReport {
+            part,
+            status,
+            answer: optional_string_from_json(json, "answer")?,
+            message: optional_string_from_json(json, "message")?,
+            combined: json
+                .get("combined")
+                .and_then(|v| v.get::<bool>().copied())
+                .unwrap_or(false),
+            // NOTE: binaries that were built before the flag was reported were benched if they took samples.
+            benched: json
+                .get("benched")
+                .and_then(|v| v.get::<bool>().copied())
+                .unwrap_or(stats.samples > 1),
+            stats,
+            heap: HeapStats::from_json(json)?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{HeapStats, PartReport, parse_budget, parse_param};
+    use crate::template::{PartStatus, stats::Stats};
+    use std::{str::FromStr, time::Duration};
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn parses_budgets() {
+        assert_eq!(parse_budget("0.5"), Some(Duration::from_millis(500)));
+        assert_eq!(parse_budget("0"), Some(Duration::ZERO));
+        for budget in ["-1", "inf", "NaN", "1e300", "soon"] {
+            assert_eq!(parse_budget(budget), None);
+        }
+    }
+
+    #[test]
+    fn parses_params() {
+        assert_eq!(parse_param("pairs=10"), Ok(("pairs".into(), "10".into())));
+        assert_eq!(parse_param("name = a=b"), Ok(("name".into(), "a=b".into())));
+        assert!(parse_param("pairs").is_err());
+        assert!(parse_param("=10").is_err());
+    }
+
+    #[test]
+    fn round_trips_reports() {
+        let report = PartReport {
+            part: 2,
+            status: PartStatus::Solved,
+            answer: Some("line 1\nline 2 (1ms @ 5 samples)".into()),
+            message: None,
+            stats: Stats::from_samples(&[
+                Duration::from_nanos(74_130),
+                Duration::from_nanos(70_000),
+                Duration::from_nanos(80_000),
+            ]),
+            combined: true,
+            benched: true,
+            heap: Some(HeapStats {
+                total_bytes: 5120,
+                peak_bytes: 4096,
+                allocations: 2,
+            }),
+        };
+        let json = JsonValue::from(&report).stringify().unwrap();
+        assert!(!json.contains('\n'));
+        let parsed = PartReport::try_from(&JsonValue::from_str(&json).unwrap()).unwrap();
+        assert_eq!(parsed, report);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_reports() {
+        let json = JsonValue::from_str(r#"{ "part": 1, "status": "solved" }"#).unwrap();
+        PartReport::try_from(&json).unwrap();
+    }
 }
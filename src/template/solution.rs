@@ -0,0 +1,249 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// An error a solution part can return instead of an answer.
+///
+/// ```
+/// # use advent_of_code::template::SolutionError;
+/// let err = SolutionError::parse(3, 5, "expected a digit");
+/// assert_eq!(err.to_string(), "parse error at line 3, column 5: expected a digit")
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolutionError {
+    /// The input could not be parsed. `line` and `column` are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input was parsed, but it has no answer.
+    Unsolvable(String),
+    /// The part has not been implemented yet.
+    /// The runner also reports a part that returns [`None`] like this, as that is what a scaffolded solution returns.
+    NotImplemented,
+    /// The part panicked. This is set by the runner and not meant to be returned by solutions.
+    Panicked(String),
+    /// The part ran longer than the timeout. This is set by the runner and not meant to be returned by solutions.
+    TimedOut,
+}
+
+impl SolutionError {
+    /// Creates a [`SolutionError::Parse`] for the given 1-based position.
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Creates a [`SolutionError::Unsolvable`] with the given reason.
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        Self::Unsolvable(message.into())
+    }
+
+    /// Returns the category of this error.
+    pub fn status(&self) -> PartStatus {
+        match self {
+            SolutionError::Parse { .. } => PartStatus::ParseError,
+            SolutionError::Unsolvable(_) => PartStatus::Unsolvable,
+            SolutionError::NotImplemented => PartStatus::NotImplemented,
+            SolutionError::Panicked(_) => PartStatus::Panicked,
+            SolutionError::TimedOut => PartStatus::TimedOut,
+        }
+    }
+}
+
+impl Error for SolutionError {}
+
+impl Display for SolutionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolutionError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "parse error at line {line}, column {column}: {message}"),
+            SolutionError::Unsolvable(message) => write!(f, "unsolvable: {message}"),
+            SolutionError::NotImplemented => f.write_str("not implemented"),
+            SolutionError::Panicked(message) => write!(f, "panicked: {message}"),
+            SolutionError::TimedOut => f.write_str("timed out"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The outcome category of running a single part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PartStatus {
+    Solved,
+    NotImplemented,
+    ParseError,
+    Unsolvable,
+    Panicked,
+    TimedOut,
+}
+
+impl PartStatus {
+    /// A short, human-readable label. Failure messages printed by the runner start with it.
+    pub fn label(self) -> &'static str {
+        match self {
+            PartStatus::Solved => "solved",
+            PartStatus::NotImplemented => "not implemented",
+            PartStatus::ParseError => "parse error",
+            PartStatus::Unsolvable => "unsolvable",
+            PartStatus::Panicked => "panicked",
+            PartStatus::TimedOut => "timed out",
+        }
+    }
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    /// Parses a status from a label, or from a failure message starting with one.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            PartStatus::Solved,
+            PartStatus::NotImplemented,
+            PartStatus::ParseError,
+            PartStatus::Unsolvable,
+            PartStatus::Panicked,
+            PartStatus::TimedOut,
+        ]
+        .into_iter()
+        .find(|status| s.trim().starts_with(status.label()))
+        .ok_or_else(|| format!("unknown part status: {s}"))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Return types the runner accepts from solution parts.
+///
+/// Implemented for [`Option<T>`], where [`None`] is reported as [`SolutionError::NotImplemented`],
+/// and for [`Result<T, E>`] with any error that converts into a [`SolutionError`].
+pub trait IntoSolution {
+    type Answer: Display;
+
+    fn into_solution(self) -> Result<Self::Answer, SolutionError>;
+}
+
+impl<T: Display> IntoSolution for Option<T> {
+    type Answer = T;
+
+    fn into_solution(self) -> Result<T, SolutionError> {
+        self.ok_or(SolutionError::NotImplemented)
+    }
+}
+
+impl<T: Display, E: Into<SolutionError>> IntoSolution for Result<T, E> {
+    type Answer = T;
+
+    fn into_solution(self) -> Result<T, SolutionError> {
+        self.map_err(Into::into)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Parameters of a puzzle that differ between the examples and the real input, e.g. the number of steps to simulate.
+/// The default values are the ones of the real input.
+///
+/// Created with the [`params!`](crate::params) macro. Solutions without parameters use `()`.
+pub trait PuzzleParams: Default {
+    /// Sets a parameter from its name and its value as text.
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String>;
+
+    /// Creates parameters from the defaults and the given values, e.g. the ones of `--param pairs=10`.
+    fn from_pairs(pairs: &[(String, String)]) -> Result<Self, String> {
+        let mut params = Self::default();
+        for (key, value) in pairs {
+            params.set(key, value)?;
+        }
+        Ok(params)
+    }
+}
+
+impl PuzzleParams for () {
+    fn set(&mut self, key: &str, _: &str) -> Result<(), String> {
+        Err(format!("unknown parameter `{key}`, this day has no parameters"))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{IntoSolution, PartStatus, PuzzleParams, SolutionError};
+
+    #[test]
+    fn converts_options() {
+        assert_eq!(Some(1).into_solution(), Ok(1));
+        assert_eq!(
+            None::<u64>.into_solution(),
+            Err(SolutionError::NotImplemented)
+        );
+    }
+
+    #[derive(Default)]
+    struct Params {
+        pairs: usize,
+    }
+
+    impl PuzzleParams for Params {
+        fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+            match key {
+                "pairs" => self.pairs = value.parse().map_err(|_| "invalid pairs")?,
+                _ => return Err(format!("unknown parameter `{key}`")),
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn sets_params_from_pairs() {
+        let pairs = [("pairs".to_string(), "10".to_string())];
+        assert_eq!(Params::from_pairs(&pairs).unwrap().pairs, 10);
+        assert!(Params::from_pairs(&[("steps".into(), "1".into())]).is_err());
+        assert!(<()>::from_pairs(&pairs).is_err());
+        assert!(<()>::from_pairs(&[]).is_ok());
+    }
+
+    #[test]
+    fn converts_results() {
+        let res: Result<u64, SolutionError> = Err(SolutionError::unsolvable("no path"));
+        assert_eq!(
+            res.into_solution().unwrap_err().status(),
+            PartStatus::Unsolvable
+        );
+    }
+
+    #[test]
+    fn parses_status_from_failure_messages() {
+        let err = SolutionError::parse(1, 2, "unexpected token");
+        assert_eq!(
+            err.to_string().parse::<PartStatus>(),
+            Ok(PartStatus::ParseError)
+        );
+        assert_eq!(
+            SolutionError::Panicked("oops".into())
+                .to_string()
+                .parse::<PartStatus>(),
+            Ok(PartStatus::Panicked)
+        );
+        assert_eq!(
+            SolutionError::TimedOut.to_string().parse::<PartStatus>(),
+            Ok(PartStatus::TimedOut)
+        );
+        assert!("✖".parse::<PartStatus>().is_err());
+    }
+}
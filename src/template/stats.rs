@@ -0,0 +1,201 @@
+/// Summary statistics of benchmark measurements.
+use std::{collections::HashMap, time::Duration};
+use tinyjson::JsonValue;
+
+/// Summary of the execution times measured for a part.
+///
+/// Measurements outside of the Tukey fences (1.5 times the interquartile range below the first or above the
+/// third quartile) are counted as outliers and left out of all other values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub stddev: Duration,
+    /// Number of measurements, including outliers.
+    pub samples: u128,
+    pub outliers: u128,
+}
+
+impl Stats {
+    /// Stats of a part that was executed once.
+    pub fn single(duration: Duration) -> Self {
+        Self {
+            mean: duration,
+            median: duration,
+            p95: duration,
+            min: duration,
+            max: duration,
+            stddev: Duration::ZERO,
+            samples: 1,
+            outliers: 0,
+        }
+    }
+
+    /// Summarizes a non-empty set of measurements.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_samples(timers: &[Duration]) -> Self {
+        let mut sorted = timers.to_vec();
+        sorted.sort_unstable();
+
+        let q1 = percentile(&sorted, 25.0);
+        let q3 = percentile(&sorted, 75.0);
+        let iqr = q3.saturating_sub(q1);
+        let lower = q1.saturating_sub(iqr.mul_f64(1.5));
+        let upper = q3.saturating_add(iqr.mul_f64(1.5));
+
+        let retained: Vec<Duration> = sorted
+            .iter()
+            .copied()
+            .filter(|t| *t >= lower && *t <= upper)
+            .collect();
+
+        let mean = retained.iter().map(Duration::as_nanos).sum::<u128>() / retained.len() as u128;
+
+        let variance = retained
+            .iter()
+            .map(|t| (t.as_nanos() as f64 - mean as f64).powi(2))
+            .sum::<f64>()
+            / retained.len() as f64;
+
+        Self {
+            #[allow(clippy::cast_possible_truncation)]
+            mean: Duration::from_nanos(mean as u64),
+            median: percentile(&retained, 50.0),
+            p95: percentile(&retained, 95.0),
+            min: retained.first().copied().unwrap_or_default(),
+            max: retained.last().copied().unwrap_or_default(),
+            stddev: Duration::from_secs_f64(variance.sqrt() / 1_000_000_000_f64),
+            samples: sorted.len() as u128,
+            outliers: (sorted.len() - retained.len()) as u128,
+        }
+    }
+
+    /// Adds the stats to a JSON object, using a `_nanos` suffix for durations.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn insert_into(&self, map: &mut HashMap<String, JsonValue>) {
+        for (key, duration) in [
+            ("duration_nanos", self.mean),
+            ("median_nanos", self.median),
+            ("p95_nanos", self.p95),
+            ("min_nanos", self.min),
+            ("max_nanos", self.max),
+            ("stddev_nanos", self.stddev),
+        ] {
+            map.insert(key.into(), JsonValue::Number(duration.as_nanos() as f64));
+        }
+
+        map.insert("samples".into(), JsonValue::Number(self.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(self.outliers as f64));
+    }
+
+    /// Reads the stats from a JSON object written by [`Stats::insert_into`].
+    pub fn from_json(json: &HashMap<String, JsonValue>) -> Result<Self, String> {
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .filter(|v| *v >= 0_f64)
+                .ok_or_else(|| format!("Expected stats.{key} to be a positive number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let duration = |key: &str| number(key).map(|v| Duration::from_nanos(v as u64));
+
+        let samples = number("samples")?;
+        if samples < 1_f64 {
+            return Err("Expected stats.samples to be at least 1.".into());
+        }
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Self {
+            mean: duration("duration_nanos")?,
+            median: duration("median_nanos")?,
+            p95: duration("p95_nanos")?,
+            min: duration("min_nanos")?,
+            max: duration("max_nanos")?,
+            stddev: duration("stddev_nanos")?,
+            samples: samples as u128,
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+impl From<&Stats> for JsonValue {
+    fn from(value: &Stats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        value.insert_into(&mut map);
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Stats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+        Stats::from_json(json)
+    }
+}
+
+/// Nearest-rank percentile of sorted, non-empty measurements.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn percentile(sorted: &[Duration], pct: f64) -> Duration {
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Stats;
+    use std::time::Duration;
+    use tinyjson::JsonValue;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_nanos(*v)).collect()
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let stats = Stats::from_samples(&nanos(&[6, 4, 4, 6, 6, 4, 4, 6]));
+        assert_eq!(stats.mean, Duration::from_nanos(5));
+        assert_eq!(stats.median, Duration::from_nanos(4));
+        assert_eq!(stats.p95, Duration::from_nanos(6));
+        assert_eq!(stats.min, Duration::from_nanos(4));
+        assert_eq!(stats.max, Duration::from_nanos(6));
+        assert_eq!(stats.stddev, Duration::from_nanos(1));
+        assert_eq!(stats.samples, 8);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = Stats::from_samples(&nanos(&[10, 11, 10, 12, 11, 10, 1000]));
+        assert_eq!(stats.samples, 7);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.max, Duration::from_nanos(12));
+        assert_eq!(stats.mean, Duration::from_nanos(10));
+    }
+
+    #[test]
+    fn handles_single_samples() {
+        let stats = Stats::from_samples(&nanos(&[42]));
+        assert_eq!(stats, Stats::single(Duration::from_nanos(42)));
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let stats = Stats::from_samples(&nanos(&[10, 11, 10, 12, 11, 10, 1000]));
+        let json = JsonValue::from(&stats);
+        assert_eq!(Stats::try_from(&json).unwrap(), stats);
+    }
+}
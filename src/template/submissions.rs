@@ -0,0 +1,527 @@
+/// Module that keeps track of submitted answers, so known wrong answers are not sent again.
+/// Every wrong answer delays the next submission, so resending one only costs time.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+fn get_path(year: Year) -> PathBuf {
+    year.data_dir().join("submissions.json")
+}
+
+/// Hint given with an incorrect answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// Outcome of a submitted answer, as told by the Advent of Code server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+    },
+    /// The part was solved before, so the answer was not checked.
+    AlreadySolved,
+    /// An answer was submitted too recently. `wait` is the time left until the next submission, if known.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The part can't be solved yet, e.g. part two before part one.
+    WrongLevel,
+}
+
+impl SubmitOutcome {
+    /// Parse the outcome from the text of the response page.
+    /// The server tells [`SubmitOutcome::AlreadySolved`] apart from [`SubmitOutcome::WrongLevel`] with the same text,
+    /// so this always parses as the latter.
+    pub fn parse(text: &str) -> Option<Self> {
+        if text.contains("That's the right answer") {
+            Some(SubmitOutcome::Correct)
+        } else if text.contains("You gave an answer too recently") {
+            Some(SubmitOutcome::RateLimited {
+                wait: parse_wait(text),
+            })
+        } else if text.contains("That's not the right answer") {
+            let hint = if text.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if text.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Some(SubmitOutcome::Incorrect { hint })
+        } else if text.contains("You don't seem to be solving the right level") {
+            Some(SubmitOutcome::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SubmitOutcome::Correct => "correct",
+            SubmitOutcome::Incorrect { hint: None } => "wrong",
+            SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+            } => "too high",
+            SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooLow),
+            } => "too low",
+            SubmitOutcome::AlreadySolved => "already solved",
+            SubmitOutcome::RateLimited { .. } => "rate limited",
+            SubmitOutcome::WrongLevel => "wrong level",
+        }
+    }
+
+    /// Whether the outcome tells that the answer is wrong.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, SubmitOutcome::Incorrect { .. })
+    }
+
+    /// Exit code of a run that submitted an answer with this outcome.
+    pub fn exit_code(self) -> i32 {
+        match self {
+            SubmitOutcome::Correct | SubmitOutcome::AlreadySolved => 0,
+            SubmitOutcome::Incorrect { .. } => 1,
+            SubmitOutcome::RateLimited { .. } => 2,
+            SubmitOutcome::WrongLevel => 3,
+        }
+    }
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+impl FromStr for SubmitOutcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            SubmitOutcome::Correct,
+            SubmitOutcome::Incorrect { hint: None },
+            SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+            },
+            SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooLow),
+            },
+            SubmitOutcome::AlreadySolved,
+            SubmitOutcome::RateLimited { wait: None },
+            SubmitOutcome::WrongLevel,
+        ]
+        .into_iter()
+        .find(|outcome| outcome.label() == s)
+        .ok_or_else(|| format!("unknown outcome: {s}"))
+    }
+}
+
+/// Parse the time left to wait, e.g. "You have 1m 5s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    text[start..end]
+        .split_whitespace()
+        .map(|part| {
+            let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Reason not to submit an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part was already solved with the given answer.
+    AlreadySolved(String),
+    /// The same answer was submitted before.
+    KnownWrong(SubmitOutcome),
+    /// The answer is not lower than an answer that was too high.
+    NotBelow(String),
+    /// The answer is not higher than an answer that was too low.
+    NotAbove(String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "this part was already solved with {answer}")
+            }
+            Refusal::KnownWrong(SubmitOutcome::Incorrect { hint: None }) => {
+                f.write_str("this answer was already wrong")
+            }
+            Refusal::KnownWrong(outcome) => {
+                write!(f, "this answer was already {outcome}")
+            }
+            Refusal::NotBelow(bound) => write!(f, "{bound} was already too high"),
+            Refusal::NotAbove(bound) => write!(f, "{bound} was already too low"),
+        }
+    }
+}
+
+/// A submitted answer and its outcome.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: SubmitOutcome,
+}
+
+/// All submitted answers.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to the JSON file of a year.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        fs::create_dir_all(year.data_dir())?;
+        let mut file = fs::File::create(get_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from the JSON file of a year. If not present, returns no submissions.
+    /// A file that can't be read or parsed is an error, so the guard never passes an answer because of it.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        Self::read_from_path(&get_path(year))
+    }
+
+    fn read_from_path(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                Submissions::try_from(contents).map_err(|e| format!("{}: {e}", path.display()))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    /// Check an answer against earlier responses for the same part.
+    /// Bounds from too high or too low answers only apply to integer answers.
+    pub fn guard(&self, day: Day, part: u8, answer: &str) -> Result<(), Refusal> {
+        let answer = answer.trim();
+        let value = answer.parse::<i128>().ok();
+
+        for submission in self.data.iter().filter(|s| s.day == day && s.part == part) {
+            let bound = submission.answer.parse::<i128>().ok();
+
+            match submission.outcome {
+                SubmitOutcome::Correct => {
+                    return Err(Refusal::AlreadySolved(submission.answer.clone()));
+                }
+                outcome if outcome.is_wrong() && submission.answer == answer => {
+                    return Err(Refusal::KnownWrong(outcome));
+                }
+                SubmitOutcome::Incorrect {
+                    hint: Some(Hint::TooHigh),
+                } if value.zip(bound).is_some_and(|(v, b)| v >= b) => {
+                    return Err(Refusal::NotBelow(submission.answer.clone()));
+                }
+                SubmitOutcome::Incorrect {
+                    hint: Some(Hint::TooLow),
+                } if value.zip(bound).is_some_and(|(v, b)| v <= b) => {
+                    return Err(Refusal::NotAbove(submission.answer.clone()));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn push(&mut self, day: Day, part: u8, answer: &str, outcome: SubmitOutcome) {
+        self.data.push(Submission {
+            day,
+            part,
+            answer: answer.trim().to_string(),
+            outcome,
+        });
+    }
+}
+
+/// Record the outcome of a submitted answer in the submissions file of a year.
+/// A file that can't be parsed is left as is, instead of being replaced by this submission.
+pub fn record(
+    year: Year,
+    day: Day,
+    part: u8,
+    answer: &str,
+    outcome: SubmitOutcome,
+) -> Result<(), String> {
+    let mut submissions = Submissions::read_from_file(year)?;
+    submissions.push(day, part, answer, outcome);
+    submissions.store_file(year).map_err(|e| e.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submissions> for JsonValue {
+    fn from(value: &Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "response".into(),
+            JsonValue::String(value.outcome.label().into()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u8)
+            .filter(|v| (1..=2).contains(v))
+            .ok_or("Expected submission.part to be 1 or 2.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let outcome = json
+            .get("response")
+            .and_then(|v| v.get::<String>())
+            .and_then(|v| SubmitOutcome::from_str(v).ok())
+            .ok_or("Expected submission.response to be an outcome.")?;
+
+        Ok(Submission {
+            day,
+            part,
+            answer,
+            outcome,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Hint, Refusal, Submissions, SubmitOutcome};
+    use crate::day;
+    use std::{fs, time::Duration};
+    use tinyjson::JsonValue;
+
+    const WRONG: SubmitOutcome = SubmitOutcome::Incorrect { hint: None };
+    const TOO_HIGH: SubmitOutcome = SubmitOutcome::Incorrect {
+        hint: Some(Hint::TooHigh),
+    };
+    const TOO_LOW: SubmitOutcome = SubmitOutcome::Incorrect {
+        hint: Some(Hint::TooLow),
+    };
+
+    #[test]
+    fn parses_outcomes() {
+        assert_eq!(
+            SubmitOutcome::parse("That's the right answer! You are one gold star closer."),
+            Some(SubmitOutcome::Correct)
+        );
+        assert_eq!(
+            SubmitOutcome::parse(
+                "That's not the right answer; your answer is too high. If you're stuck, ..."
+            ),
+            Some(TOO_HIGH)
+        );
+        assert_eq!(
+            SubmitOutcome::parse("That's not the right answer; your answer is too low."),
+            Some(TOO_LOW)
+        );
+        assert_eq!(
+            SubmitOutcome::parse("That's not the right answer. If you're stuck, ..."),
+            Some(WRONG)
+        );
+        assert_eq!(
+            SubmitOutcome::parse(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ),
+            Some(SubmitOutcome::WrongLevel)
+        );
+        assert_eq!(SubmitOutcome::parse("Something else."), None);
+    }
+
+    #[test]
+    fn parses_wait_times() {
+        assert_eq!(
+            SubmitOutcome::parse(
+                "You gave an answer too recently; you have to wait. You have 45s left to wait."
+            ),
+            Some(SubmitOutcome::RateLimited {
+                wait: Some(Duration::from_secs(45))
+            })
+        );
+        assert_eq!(
+            SubmitOutcome::parse(
+                "You gave an answer too recently; you have to wait. You have 1m 5s left to wait."
+            ),
+            Some(SubmitOutcome::RateLimited {
+                wait: Some(Duration::from_secs(65))
+            })
+        );
+        assert_eq!(
+            SubmitOutcome::parse("You gave an answer too recently."),
+            Some(SubmitOutcome::RateLimited { wait: None })
+        );
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let mut submissions = Submissions::default();
+        submissions.push(day!(1), 1, "abc", WRONG);
+
+        assert_eq!(
+            submissions.guard(day!(1), 1, "abc"),
+            Err(Refusal::KnownWrong(WRONG))
+        );
+        assert_eq!(submissions.guard(day!(1), 1, "abd"), Ok(()));
+        assert_eq!(submissions.guard(day!(1), 2, "abc"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_answers_outside_of_bounds() {
+        let mut submissions = Submissions::default();
+        submissions.push(day!(9), 2, "1000", TOO_HIGH);
+        submissions.push(day!(9), 2, "500", TOO_LOW);
+        submissions.push(day!(9), 2, "600", SubmitOutcome::RateLimited { wait: None });
+
+        assert_eq!(
+            submissions.guard(day!(9), 2, "1000"),
+            Err(Refusal::KnownWrong(TOO_HIGH))
+        );
+        assert_eq!(
+            submissions.guard(day!(9), 2, "1200"),
+            Err(Refusal::NotBelow("1000".into()))
+        );
+        assert_eq!(
+            submissions.guard(day!(9), 2, "-3"),
+            Err(Refusal::NotAbove("500".into()))
+        );
+        assert_eq!(submissions.guard(day!(9), 2, "600"), Ok(()));
+        assert_eq!(submissions.guard(day!(9), 2, "abc"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let mut submissions = Submissions::default();
+        submissions.push(day!(4), 1, "17", SubmitOutcome::Correct);
+
+        assert_eq!(
+            submissions.guard(day!(4), 1, "18"),
+            Err(Refusal::AlreadySolved("17".into()))
+        );
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let mut submissions = Submissions::default();
+        submissions.push(day!(4), 1, "17", TOO_LOW);
+        submissions.push(day!(4), 2, "x", SubmitOutcome::RateLimited { wait: None });
+        submissions.push(day!(5), 2, "1", SubmitOutcome::WrongLevel);
+
+        let json = JsonValue::from(&submissions).stringify().unwrap();
+        assert_eq!(Submissions::try_from(json).unwrap(), submissions);
+    }
+
+    #[test]
+    fn reads_missing_files_as_empty() {
+        let path =
+            std::env::temp_dir().join(format!("aoc-submissions-{}.json", std::process::id()));
+        assert_eq!(
+            Submissions::read_from_path(&path),
+            Ok(Submissions::default())
+        );
+    }
+
+    #[test]
+    fn rejects_unparsable_files() {
+        let path =
+            std::env::temp_dir().join(format!("aoc-submissions-bad-{}.json", std::process::id()));
+        fs::write(&path, "{ \"data\": [").unwrap();
+        let result = Submissions::read_from_path(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+}
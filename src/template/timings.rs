@@ -1,19 +1,111 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    path::PathBuf,
+    str::FromStr,
+    time::Duration,
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::heap::HeapStats;
+use crate::template::runner::PARSE_PART;
+use crate::template::stats::Stats;
+use crate::template::{Day, Year};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+fn get_path(year: Year) -> PathBuf {
+    year.data_dir().join("timings.json")
+}
+
+/// Version of the JSON schema written by [`Timings::store_file`].
+/// Files without a version store preformatted durations such as `"74.13ns"` and are migrated when read. Files with a
+/// newer version are written by a newer template and rejected.
+const SCHEMA_VERSION: f64 = 2.0;
+
+/// Represents the benchmark time of a single part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartTiming {
+    pub mean: Duration,
+    /// Spread of the measurements. Missing for timings that were migrated from preformatted durations.
+    pub stats: Option<Stats>,
+    /// Heap usage of the part. Missing for timings that were stored before it was measured.
+    pub heap: Option<HeapStats>,
+    /// The part ran longer than the timeout, which is stored as the mean.
+    pub timed_out: bool,
+}
+
+impl PartTiming {
+    pub fn new(mean: Duration) -> Self {
+        Self {
+            mean,
+            stats: None,
+            heap: None,
+            timed_out: false,
+        }
+    }
+
+    pub fn from_stats(stats: Stats) -> Self {
+        Self {
+            mean: stats.mean,
+            stats: Some(stats),
+            heap: None,
+            timed_out: false,
+        }
+    }
+
+    /// A part that was stopped after running for `timeout`.
+    pub fn timed_out(timeout: Duration) -> Self {
+        Self {
+            timed_out: true,
+            ..Self::new(timeout)
+        }
+    }
+
+    #[must_use]
+    pub fn with_heap(self, heap: Option<HeapStats>) -> Self {
+        Self { heap, ..self }
+    }
+
+    pub fn samples(&self) -> Option<u128> {
+        self.stats.as_ref().map(|s| s.samples)
+    }
+}
+
+impl Display for PartTiming {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.timed_out {
+            f.write_str("⏱ timed out")
+        } else {
+            write!(f, "{:.1?}", self.mean)
+        }
+    }
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    /// The parse phase of days whose `solution!` has a `parse` function, see [`PARSE_PART`].
+    pub parse: Option<PartTiming>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
+    /// Both parts were solved in one pass, whose timing is stored as `part_1`.
+    pub combined: bool,
     pub total_nanos: f64,
 }
 
+impl Timing {
+    pub fn part(&self, part: u8) -> Option<&PartTiming> {
+        match part {
+            PARSE_PART => self.parse.as_ref(),
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
@@ -22,19 +114,26 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate timings to the JSON file of a year.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        fs::create_dir_all(year.data_dir())?;
+        let mut file = fs::File::create(get_path(year))?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from)
-            .unwrap_or_default()
+    /// Rehydrate timings from the JSON file of a year. If not present, returns empty timings.
+    /// Files in the old format are migrated, and written in the new format the next time they are stored.
+    /// A file that can't be read or parsed, e.g. of a newer schema version, is an error, so it is never overwritten.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        let path = get_path(year);
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                Timings::try_from(contents).map_err(|e| format!("{}: {e}", path.display()))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
@@ -51,7 +150,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -60,10 +159,12 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
+    /// Whether every part of a day has a timing. Parts that timed out don't count.
     pub fn is_day_complete(&self, day: Day) -> bool {
+        let is_timed = |part: &Option<PartTiming>| part.as_ref().is_some_and(|p| !p.timed_out);
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.day == day && is_timed(&t.part_1) && (is_timed(&t.part_2) || t.combined))
     }
 }
 
@@ -73,6 +174,7 @@ impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert("version".into(), JsonValue::Number(SCHEMA_VERSION));
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
@@ -88,9 +190,23 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        // NOTE: files without a version are migrated, see `SCHEMA_VERSION`.
+        match json.get("version").map(|v| v.get::<f64>()) {
+            None => {}
+            Some(Some(version)) if *version <= SCHEMA_VERSION => {}
+            Some(Some(version)) => {
+                return Err(format!(
+                    "schema version {version} is newer than the supported version {SCHEMA_VERSION}, update the template."
+                ));
+            }
+            Some(None) => return Err("expected `json.version` to be a number.".into()),
+        }
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
@@ -107,6 +223,90 @@ impl TryFrom<String> for Timings {
 
 /* -------------------------------------------------------------------------- */
 
+impl From<&PartTiming> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        match &value.stats {
+            Some(stats) => stats.insert_into(&mut map),
+            None => {
+                map.insert(
+                    "duration_nanos".into(),
+                    JsonValue::Number(value.mean.as_nanos() as f64),
+                );
+            }
+        }
+
+        if let Some(heap) = &value.heap {
+            heap.insert_into(&mut map);
+        }
+
+        if value.timed_out {
+            map.insert("timed_out".into(), JsonValue::Boolean(true));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartTiming {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        // NOTE: timings without a schema version store preformatted durations.
+        if let Some(legacy) = value.get::<String>() {
+            return parse_duration(legacy)
+                .map(PartTiming::new)
+                .ok_or_else(|| format!("Could not parse duration `{legacy}`."));
+        }
+
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part timing to be a JSON object or string.")?;
+
+        let heap = HeapStats::from_json(json)?;
+        let timed_out = json
+            .get("timed_out")
+            .and_then(|v| v.get::<bool>().copied())
+            .unwrap_or(false);
+
+        if json.contains_key("samples") {
+            return Stats::from_json(json)
+                .map(|stats| PartTiming::from_stats(stats).with_heap(heap));
+        }
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        json.get("duration_nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .filter(|v| *v >= 0_f64)
+            .map(|v| PartTiming {
+                timed_out,
+                ..PartTiming::new(Duration::from_nanos(v as u64)).with_heap(heap)
+            })
+            .ok_or_else(|| "Expected part timing to have a `duration_nanos`.".into())
+    }
+}
+
+/// Parse a duration formatted with `{:?}`, e.g. `74.13ns`, `1.1ms` or `23.2s`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let parse_to_float = |postfix: &str| s.strip_suffix(postfix)?.parse::<f64>().ok();
+
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    let nanos = if s.ends_with("ns") {
+        parse_to_float("ns")
+    } else if s.ends_with("µs") {
+        parse_to_float("µs").map(|x| x * 1000_f64)
+    } else if s.ends_with("ms") {
+        parse_to_float("ms").map(|x| x * 1_000_000_f64)
+    } else {
+        parse_to_float("s").map(|x| x * 1_000_000_000_f64)
+    }?;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    (nanos >= 0_f64).then(|| Duration::from_nanos(nanos.round() as u64))
+}
+
 impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
@@ -114,24 +314,24 @@ impl From<&Timing> for JsonValue {
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        // NOTE: only days with a parse phase store one, so files of other days stay the same.
+        if let Some(parse) = &value.parse {
+            map.insert("parse".into(), JsonValue::from(parse));
+        }
 
-        map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+        if value.combined {
+            map.insert("combined".into(), JsonValue::Boolean(true));
+        }
 
-        map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+        for (key, part) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            map.insert(
+                key.into(),
+                match part {
+                    Some(x) => JsonValue::from(x),
+                    None => JsonValue::Null,
+                },
+            );
+        }
 
         JsonValue::Object(map)
     }
@@ -151,25 +351,44 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        let part = |key: &str| -> Result<Option<PartTiming>, String> {
+            let value = json
+                .get(key)
+                .ok_or_else(|| format!("Expected timing.{key} to be null or a part timing."))?;
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+            if value.is_null() {
+                return Ok(None);
+            }
+
+            let mut part = PartTiming::try_from(value)?;
+
+            // NOTE: migrate stats that were stored next to preformatted durations.
+            if let Some(stats) = json.get(&format!("{key}_stats")) {
+                part = PartTiming::from_stats(Stats::try_from(stats)?);
+            }
+
+            Ok(Some(part))
+        };
 
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        let parse = match json.get("parse") {
+            Some(value) if !value.is_null() => Some(PartTiming::try_from(value)?),
+            _ => None,
+        };
+
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            parse,
+            part_1: part("part_1")?,
+            part_2: part("part_2")?,
+            combined: json
+                .get("combined")
+                .and_then(|v| v.get::<bool>().copied())
+                .unwrap_or(false),
             total_nanos,
         })
     }
@@ -180,28 +399,35 @@ impl TryFrom<&JsonValue> for Timing {
 #[cfg(feature = "test_lib")]
 mod tests {
     use crate::day;
+    use std::time::Duration;
 
-    use super::{Timing, Timings};
+    use super::{PartTiming, Timing, Timings};
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse: None,
+                    part_1: Some(PartTiming::new(Duration::from_millis(10))),
+                    part_2: Some(PartTiming::new(Duration::from_millis(20))),
+                    combined: false,
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse: None,
+                    part_1: Some(PartTiming::new(Duration::from_millis(30))),
+                    part_2: Some(PartTiming::new(Duration::from_millis(40))),
+                    combined: false,
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
+                    parse: None,
+                    part_1: Some(PartTiming::new(Duration::from_millis(40))),
                     part_2: None,
+                    combined: false,
                     total_nanos: 4e+10,
                 },
             ],
@@ -209,18 +435,77 @@ mod tests {
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day,
+            template::timings::{PartTiming, Timings},
+        };
+        use std::time::Duration;
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "duration_nanos": 1000000, "median_nanos": 1000000, "p95_nanos": 1200000, "min_nanos": 900000, "max_nanos": 1300000, "stddev_nanos": 10000, "samples": 100, "outliers": 2 }, "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            let part_1 = timing.part_1.as_ref().unwrap();
+            assert_eq!(part_1.mean, Duration::from_millis(1));
+            assert_eq!(part_1.samples(), Some(100));
+            assert_eq!(part_1.stats.as_ref().unwrap().outliers, 2);
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.total_nanos, 1_000_000_f64);
+        }
+
+        #[test]
+        fn handles_parts_without_stats() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "duration_nanos": 1500 }, "part_2": null, "total_nanos": 1500 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(
+                timing.part_1,
+                Some(PartTiming::new(Duration::from_nanos(1500)))
+            );
+        }
+
+        #[test]
+        fn rejects_newer_schema_versions() {
+            let json = r#"{ "version": 3, "data": [] }"#.to_string();
+            assert!(Timings::try_from(json).is_err());
+            let json = r#"{ "version": "2", "data": [] }"#.to_string();
+            assert!(Timings::try_from(json).is_err());
+        }
+
+        #[test]
+        fn migrates_formatted_durations() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "74.13ns", "part_2": "1.5ms", "total_nanos": 1500074.13 }, { "day": "02", "part_1": "23.2s", "part_2": "12.3µs", "total_nanos": 0 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let part = |day: usize, part: u8| timings.data[day].part(part).unwrap().mean;
+            assert_eq!(part(0, 1), Duration::from_nanos(74));
+            assert_eq!(part(0, 2), Duration::from_micros(1500));
+            assert_eq!(part(1, 1), Duration::from_millis(23_200));
+            assert_eq!(part(1, 2), Duration::from_nanos(12_300));
+            assert_eq!(timings.data[0].part_1.as_ref().unwrap().stats, None);
+        }
+
+        #[test]
+        fn migrates_separately_stored_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "duration_nanos": 1000000, "median_nanos": 1000000, "p95_nanos": 1200000, "min_nanos": 900000, "max_nanos": 1300000, "stddev_nanos": 10000, "samples": 100, "outliers": 2 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let part_1 = timing.part_1.as_ref().unwrap();
+            assert_eq!(part_1.samples(), Some(100));
+            assert_eq!(
+                part_1.stats.as_ref().unwrap().p95,
+                Duration::from_micros(1200)
+            );
             assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_unparseable_durations() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "fast", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
         }
 
         #[test]
@@ -247,7 +532,12 @@ mod tests {
 
     mod serialization {
         use super::get_mock_timings;
-        use std::collections::HashMap;
+        use crate::template::{
+            heap::HeapStats,
+            stats::Stats,
+            timings::{PartTiming, Timings},
+        };
+        use std::{collections::HashMap, time::Duration};
         use tinyjson::JsonValue;
 
         #[test]
@@ -266,26 +556,73 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn round_trips_timings() {
+            let mut timings = get_mock_timings();
+            timings.data[0].part_1 = Some(PartTiming::from_stats(Stats::single(
+                Duration::from_nanos(42),
+            )));
+            timings.data[1].parse = Some(PartTiming::new(Duration::from_nanos(7)));
+            timings.data[1].part_2 = Some(PartTiming::timed_out(Duration::from_secs(10)));
+            timings.data[2].combined = true;
+            timings.data[2].part_1 = timings.data[2].part_1.clone().map(|part| {
+                part.with_heap(Some(HeapStats {
+                    total_bytes: 2048,
+                    peak_bytes: 1024,
+                    allocations: 3,
+                }))
+            });
+
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+
+            for (a, b) in timings.data.iter().zip(&parsed.data) {
+                assert_eq!(a.parse, b.parse);
+                assert_eq!(a.combined, b.combined);
+                assert_eq!(a.part_1, b.part_1);
+                assert_eq!(a.part_2, b.part_2);
+            }
+        }
     }
 
     mod is_day_complete {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::timings::{PartTiming, Timing, Timings},
         };
+        use std::time::Duration;
 
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
+                    parse: None,
+                    part_1: Some(PartTiming::new(Duration::from_millis(1))),
+                    part_2: Some(PartTiming::new(Duration::from_millis(2))),
+                    combined: false,
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
+        }
+
+        #[test]
+        fn handles_combined_days() {
+            let timings = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: Some(PartTiming::new(Duration::from_millis(1))),
+                    part_2: None,
+                    combined: true,
+                    total_nanos: 1_000_000_f64,
+                }],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -293,13 +630,15 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
+                    parse: None,
+                    part_1: Some(PartTiming::new(Duration::from_millis(1))),
                     part_2: None,
+                    combined: false,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -307,21 +646,40 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    combined: false,
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+
+        #[test]
+        fn handles_timed_out_days() {
+            let timings = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: Some(PartTiming::new(Duration::from_millis(1))),
+                    part_2: Some(PartTiming::timed_out(Duration::from_secs(10))),
+                    combined: false,
+                    total_nanos: 1_000_000_f64,
+                }],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
     mod merge {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::timings::{PartTiming, Timing, Timings},
         };
+        use std::time::Duration;
 
         use super::get_mock_timings;
 
@@ -331,8 +689,10 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    combined: false,
                     total_nanos: 0_f64,
                 }],
             };
@@ -351,8 +711,10 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    combined: false,
                     total_nanos: 0_f64,
                 }],
             };
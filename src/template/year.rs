@@ -0,0 +1,142 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::template::{Day, DayFromStrError, config};
+
+/// The first year of advent of code.
+const FIRST_YEAR: u16 = 2015;
+
+/// The first year with an event of 12 days. Earlier events have 25 days, unless the config says otherwise.
+const FIRST_SHORT_EVENT: u16 = 2025;
+
+/// A valid year of advent of code (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four-digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2025).unwrap();
+/// assert_eq!(year.to_string(), "2025")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The last day of the event of this year, as set in the `events` of the config. Events that are not listed
+    /// there have 25 days up to 2024 and 12 days since 2025.
+    pub fn last_day(self) -> u8 {
+        config()
+            .event_days
+            .get(&self)
+            .copied()
+            .unwrap_or_else(|| self.default_last_day())
+    }
+
+    const fn default_last_day(self) -> u8 {
+        if self.0 < FIRST_SHORT_EVENT { 25 } else { 12 }
+    }
+
+    /// Creates a [`Day`] of the event of this year, returns [`None`] if the event does not have that day.
+    pub fn day(self, day: u8) -> Option<Day> {
+        Day::new(day).filter(|_| day <= self.last_day())
+    }
+
+    /// Parses a day of the event of this year. The error states the range of days of the event.
+    pub fn parse_day(self, s: &str) -> Result<Day, DayFromStrError> {
+        Day::from_str_until(s, self.last_day())
+    }
+
+    /// Directory that holds the inputs, examples and puzzles of this year, as well as its answers and timings.
+    pub fn data_dir(self) -> PathBuf {
+        config().data_dir.join(self.to_string())
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year from {FIRST_YEAR} on")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const {
+            $crate::template::Year::new($year)
+                .expect("invalid year, expecting a value from 2015 on")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+    use crate::day;
+    use std::path::PathBuf;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2025".parse::<Year>().unwrap(), year!(2025));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("next".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn knows_the_days_of_each_event() {
+        assert_eq!(year!(2024).last_day(), 25);
+        assert_eq!(year!(2025).last_day(), 12);
+        assert_eq!(year!(2015).day(25), Some(day!(25)));
+        assert_eq!(year!(2025).day(13), None);
+        assert!(year!(2025).parse_day("13").is_err());
+    }
+
+    #[test]
+    fn keys_data_by_year() {
+        assert_eq!(year!(2024).data_dir(), PathBuf::from("data/2024"));
+    }
+}